@@ -1,8 +1,12 @@
+use crate::chord_builder::ChordBuilder;
+use crate::chord_scale::{compatible_scales, ChordScale};
+use crate::identify::{identify, ChordCandidate};
 use crate::iter::Pitches;
 use crate::iter::{MatchIndices, MatchIndicesInternal, RMatchIndices};
 use crate::iter::{Matches, MatchesInternal, RMatches};
+use crate::neo_riemannian::triad_coordinates;
 use crate::pattern::Pattern;
-use crate::{IntervalSet, Pitch};
+use crate::{IntervalSet, Pitch, Transformation, Voicing};
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
@@ -22,6 +26,15 @@ impl Chord {
         Chord { set }
     }
 
+    pub(crate) fn from_set(set: IntervalSet) -> Chord {
+        Chord { set }
+    }
+
+    /// A `ChordBuilder` for editing a copy of this chord by degree.
+    pub fn builder(&self) -> ChordBuilder {
+        ChordBuilder::new(self)
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.set.len()
@@ -45,6 +58,35 @@ impl Chord {
         self.set.as_mut_slice()
     }
 
+    /// The named chords the sounding `pitches` could be, most plausible first,
+    /// including inversions, slash chords and rootless voicings.
+    pub fn identify(pitches: &[Pitch]) -> Vec<ChordCandidate> {
+        identify(pitches)
+    }
+
+    /// The catalogued scales that fit the chord on `root`, with their
+    /// tensions and avoid notes; those with the fewest avoid notes first.
+    pub fn compatible_scales(&self, root: Pitch) -> Vec<ChordScale> {
+        compatible_scales(root, self)
+    }
+
+    /// The close-position `Voicing` of the chord on `root`.
+    pub fn voicing(&self, root: Pitch) -> Voicing {
+        Voicing::close(self, root)
+    }
+
+    /// The triad this major or minor triad on `root` becomes under
+    /// `transformation`, with its root in octave zero.
+    pub fn transform(&self, root: Pitch, transformation: Transformation) -> Option<(Pitch, Chord)> {
+        transformation.apply(root, self)
+    }
+
+    /// The Tonnetz coordinates of the root, third and fifth of this major or
+    /// minor triad on `root`.
+    pub fn tonnetz(&self, root: Pitch) -> Option<[(i8, i8); 3]> {
+        triad_coordinates(root, self)
+    }
+
     pub fn pitches(&self, root: Pitch) -> Pitches<'_> {
         Pitches {
             root,
@@ -0,0 +1,222 @@
+use crate::errors::{ChordError, ChordErrorKind};
+use crate::{Chord, IntervalSet};
+
+/// The kind of seventh added by `ChordBuilder::with_seventh`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Seventh {
+    /// Eleven semitones above the root.
+    Major,
+    /// Ten semitones above the root.
+    Minor,
+    /// Nine semitones above the root.
+    Diminished,
+}
+
+/// What has been asked of a degree so far.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Edit {
+    Natural,
+    Altered(i8),
+    Omitted,
+}
+
+/// Edits a `Chord` by scale degree rather than by semitone, e.g.
+/// `chord.builder().with_seventh(Seventh::Minor).flat(9).build()`.
+///
+/// The first rejected edit is kept and returned by `build`; later edits are
+/// ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordBuilder {
+    set: IntervalSet,
+    edits: Vec<(u8, Edit)>,
+    error: Option<ChordError>,
+}
+
+/// The degree an extension belongs with, so that 2 is treated as the ninth.
+fn family(degree: u8) -> Option<u8> {
+    match degree {
+        2 | 9 => Some(9),
+        4 | 11 => Some(11),
+        6 | 13 => Some(13),
+        3 | 5 | 7 => Some(degree),
+        _ => None,
+    }
+}
+
+/// The interval of the natural form of `degree`.
+fn natural(degree: u8) -> u8 {
+    match degree {
+        2 => 2,
+        4 => 5,
+        5 => 7,
+        6 => 9,
+        9 => 14,
+        11 => 17,
+        _ => 21,
+    }
+}
+
+/// The intervals that count as a form of the degree `family`.
+fn forms(family: u8) -> &'static [u8] {
+    match family {
+        3 => &[3, 4],
+        5 => &[6, 7, 8],
+        7 => &[10, 11],
+        9 => &[13, 14, 15],
+        11 => &[17, 18],
+        _ => &[20, 21],
+    }
+}
+
+impl ChordBuilder {
+    /// Creates a new `ChordBuilder` starting from `chord`.
+    pub fn new(chord: &Chord) -> ChordBuilder {
+        ChordBuilder {
+            set: chord.iter().collect(),
+            edits: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Add the natural form of `degree`: 2, 4, 6, 9, 11 or 13.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, degree: u8) -> ChordBuilder {
+        self.edit(degree, |b, family| {
+            if !matches!(degree, 2 | 4 | 6 | 9 | 11 | 13) {
+                return Err(ChordErrorKind::InvalidDegree);
+            }
+            if b.edited(family, |e| e != Edit::Natural) {
+                return Err(ChordErrorKind::Conflict);
+            }
+            b.set.insert(natural(degree));
+            b.edits.push((family, Edit::Natural));
+            Ok(())
+        })
+    }
+
+    /// Raise the fifth, ninth or eleventh a semitone.
+    pub fn sharp(self, degree: u8) -> ChordBuilder {
+        self.alter(degree, 1)
+    }
+
+    /// Lower the fifth, ninth or thirteenth a semitone.
+    pub fn flat(self, degree: u8) -> ChordBuilder {
+        self.alter(degree, -1)
+    }
+
+    /// Remove every form of the third, fifth, seventh, ninth, eleventh or
+    /// thirteenth.
+    pub fn omit(self, degree: u8) -> ChordBuilder {
+        self.edit(degree, |b, family| {
+            if b.edited(family, |e| e != Edit::Omitted) {
+                return Err(ChordErrorKind::Conflict);
+            }
+            let forms = forms(family);
+            b.set.retain(|i| !forms.contains(i));
+            b.edits.push((family, Edit::Omitted));
+            Ok(())
+        })
+    }
+
+    /// Replace the third with a perfect fourth.
+    pub fn sus4(self) -> ChordBuilder {
+        self.suspend(5)
+    }
+
+    /// Replace the third with a major second.
+    pub fn sus2(self) -> ChordBuilder {
+        self.suspend(2)
+    }
+
+    /// Replace any seventh with `seventh`.
+    pub fn with_seventh(self, seventh: Seventh) -> ChordBuilder {
+        self.edit(7, |b, _| {
+            if b.edited(7, |e| e == Edit::Omitted) {
+                return Err(ChordErrorKind::Conflict);
+            }
+            // Over a diminished fifth a major sixth is a diminished seventh.
+            let diminished = b.set.binary_search(&6).is_ok();
+            b.set
+                .retain(|&i| i != 10 && i != 11 && !(diminished && i == 9));
+            b.set.insert(match seventh {
+                Seventh::Major => 11,
+                Seventh::Minor => 10,
+                Seventh::Diminished => 9,
+            });
+            b.edits.push((7, Edit::Natural));
+            Ok(())
+        })
+    }
+
+    /// The edited chord, or the first edit that was rejected.
+    pub fn build(self) -> Result<Chord, ChordError> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(Chord::from_set(self.set)),
+        }
+    }
+
+    fn edit<F>(mut self, degree: u8, f: F) -> ChordBuilder
+    where
+        F: FnOnce(&mut ChordBuilder, u8) -> Result<(), ChordErrorKind>,
+    {
+        if self.error.is_some() {
+            return self;
+        }
+        let result = match family(degree) {
+            Some(family) => f(&mut self, family),
+            None => Err(ChordErrorKind::InvalidDegree),
+        };
+        if let Err(kind) = result {
+            self.error = Some(ChordError::new(kind, degree));
+        }
+        self
+    }
+
+    fn edited<P>(&self, family: u8, predicate: P) -> bool
+    where
+        P: Fn(Edit) -> bool,
+    {
+        self.edits.iter().any(|&(f, e)| f == family && predicate(e))
+    }
+
+    fn alter(self, degree: u8, delta: i8) -> ChordBuilder {
+        self.edit(degree, |b, family| {
+            let allowed = match delta {
+                1 => matches!(family, 5 | 9 | 11),
+                _ => matches!(family, 5 | 9 | 13),
+            };
+            if !allowed {
+                return Err(ChordErrorKind::InvalidAlteration);
+            }
+            // Both altered ninths are idiomatic; other opposite alterations
+            // of one degree are not.
+            let conflict = b.edited(family, |e| match e {
+                Edit::Altered(d) => d != delta && family != 9,
+                _ => true,
+            });
+            if conflict {
+                return Err(ChordErrorKind::Conflict);
+            }
+            // Replace whatever form of the degree the chord already has, but
+            // keep the other altered ninth.
+            let natural = natural(family);
+            let opposite = (natural as i8 - delta) as u8;
+            let forms = forms(family);
+            b.set
+                .retain(|i| !forms.contains(i) || family == 9 && *i == opposite);
+            b.set.insert((natural as i8 + delta) as u8);
+            b.edits.push((family, Edit::Altered(delta)));
+            Ok(())
+        })
+    }
+
+    fn suspend(self, interval: u8) -> ChordBuilder {
+        self.edit(3, |b, _| {
+            b.set.retain(|&i| i != 3 && i != 4);
+            b.set.insert(interval);
+            b.edits.push((3, Edit::Omitted));
+            Ok(())
+        })
+    }
+}
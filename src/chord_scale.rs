@@ -0,0 +1,85 @@
+use crate::pattern::Pattern;
+use crate::OCTAVE;
+use crate::{Chord, Pitch, Scale};
+
+/// A scale heard over a chord, with its tones sorted into chord tones,
+/// available tensions and avoid notes.
+///
+/// Tensions and avoid notes are given as compound intervals above the chord
+/// root where they have a conventional name above the octave, so that the
+/// flat ninth is 13 and the eleventh is 17.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordScale {
+    /// The chord root, in octave zero.
+    pub root: Pitch,
+    pub chord: Chord,
+    /// The scale, rotated to start on `root`.
+    pub scale: Scale,
+    /// The catalogue name of `scale`, if it has one.
+    pub name: Option<&'static str>,
+    /// The chord intervals, reduced to the octave.
+    pub chord_tones: Vec<u8>,
+    /// Scale tones that can be added to the chord.
+    pub tensions: Vec<u8>,
+    /// Scale tones a semitone above a chord tone, which clash with it.
+    pub avoid_notes: Vec<u8>,
+}
+
+/// The intervals above the root of a chord as pitch classes, sorted.
+fn pitch_classes(intervals: &[u8]) -> Vec<u8> {
+    let mut pcs: Vec<u8> = intervals.iter().map(|i| i % OCTAVE).collect();
+    pcs.sort_unstable();
+    pcs.dedup();
+    pcs
+}
+
+fn compound(pc: u8) -> u8 {
+    match pc {
+        1 | 2 | 3 | 5 | 6 | 8 | 9 => pc + OCTAVE,
+        _ => pc,
+    }
+}
+
+/// Sorts the tones of `scale`, rotated to `root`, around `chord`. Returns
+/// `None` if the chord is not contained in the scale.
+pub(crate) fn chord_scale(root: Pitch, chord: &Chord, scale: Scale) -> Option<ChordScale> {
+    let chord_tones = pitch_classes(chord);
+    if !chord_tones.as_slice().is_tonic_of(&scale) {
+        return None;
+    }
+    // Dominant chords take every altered ninth, eleventh and thirteenth; only
+    // the natural eleventh clashes with their third.
+    let dominant = chord_tones.contains(&4) && chord_tones.contains(&10);
+    let (avoid_notes, tensions): (Vec<u8>, Vec<u8>) = scale
+        .iter()
+        .copied()
+        .filter(|pc| chord_tones.binary_search(pc).is_err())
+        .map(compound)
+        .partition(|&i| {
+            let pc = i % OCTAVE;
+            if dominant {
+                pc == 5
+            } else {
+                chord_tones.contains(&((pc + OCTAVE - 1) % OCTAVE))
+            }
+        });
+    Some(ChordScale {
+        root: Pitch::from_interval(root.as_interval() % OCTAVE),
+        chord: chord.clone(),
+        name: scale.name(),
+        scale,
+        chord_tones,
+        tensions,
+        avoid_notes,
+    })
+}
+
+/// The catalogued scales that contain `chord` on their tonic, those with the
+/// fewest avoid notes first.
+pub(crate) fn compatible_scales(root: Pitch, chord: &Chord) -> Vec<ChordScale> {
+    let mut found: Vec<ChordScale> = Scale::catalog()
+        .filter_map(|(_, scale)| chord_scale(root, chord, scale))
+        .collect();
+    found.sort_by_key(|c| c.avoid_notes.len());
+    found
+}
@@ -0,0 +1,124 @@
+//! Interval tables shared across the crate.
+
+// Triads
+pub(crate) const MAJOR: &[u8] = &[0, 4, 7];
+pub(crate) const MINOR: &[u8] = &[0, 3, 7];
+pub(crate) const DIMINISHED: &[u8] = &[0, 3, 6];
+pub(crate) const AUGMENTED: &[u8] = &[0, 4, 8];
+pub(crate) const SUS2: &[u8] = &[0, 2, 7];
+pub(crate) const SUS4: &[u8] = &[0, 5, 7];
+pub(crate) const POWER: &[u8] = &[0, 7];
+pub(crate) const QUARTAL: &[u8] = &[0, 5, 10];
+
+// Sixths and added tones
+pub(crate) const MAJOR_6: &[u8] = &[0, 4, 7, 9];
+pub(crate) const MINOR_6: &[u8] = &[0, 3, 7, 9];
+pub(crate) const SIX_NINE: &[u8] = &[0, 4, 7, 9, 14];
+pub(crate) const ADD_9: &[u8] = &[0, 4, 7, 14];
+pub(crate) const MINOR_ADD_9: &[u8] = &[0, 3, 7, 14];
+
+// Sevenths
+pub(crate) const MAJOR_7: &[u8] = &[0, 4, 7, 11];
+pub(crate) const DOMINANT_7: &[u8] = &[0, 4, 7, 10];
+pub(crate) const MINOR_7: &[u8] = &[0, 3, 7, 10];
+pub(crate) const MINOR_MAJOR_7: &[u8] = &[0, 3, 7, 11];
+pub(crate) const HALF_DIMINISHED_7: &[u8] = &[0, 3, 6, 10];
+pub(crate) const DIMINISHED_7: &[u8] = &[0, 3, 6, 9];
+pub(crate) const AUGMENTED_MAJOR_7: &[u8] = &[0, 4, 8, 11];
+pub(crate) const AUGMENTED_7: &[u8] = &[0, 4, 8, 10];
+pub(crate) const DOMINANT_7_SUS4: &[u8] = &[0, 5, 7, 10];
+
+// Ninths
+pub(crate) const MAJOR_9: &[u8] = &[0, 4, 7, 11, 14];
+pub(crate) const DOMINANT_9: &[u8] = &[0, 4, 7, 10, 14];
+pub(crate) const MINOR_9: &[u8] = &[0, 3, 7, 10, 14];
+pub(crate) const MINOR_MAJOR_9: &[u8] = &[0, 3, 7, 11, 14];
+pub(crate) const HALF_DIMINISHED_9: &[u8] = &[0, 3, 6, 10, 14];
+pub(crate) const AUGMENTED_MAJOR_9: &[u8] = &[0, 4, 8, 11, 14];
+pub(crate) const DOMINANT_7_FLAT_9: &[u8] = &[0, 4, 7, 10, 13];
+pub(crate) const DOMINANT_7_SHARP_9: &[u8] = &[0, 4, 7, 10, 15];
+pub(crate) const MINOR_7_FLAT_9: &[u8] = &[0, 3, 7, 10, 13];
+pub(crate) const HALF_DIMINISHED_7_FLAT_9: &[u8] = &[0, 3, 6, 10, 13];
+
+// Elevenths and thirteenths
+pub(crate) const DOMINANT_11: &[u8] = &[0, 4, 7, 10, 14, 17];
+pub(crate) const MINOR_11: &[u8] = &[0, 3, 7, 10, 14, 17];
+pub(crate) const MAJOR_13: &[u8] = &[0, 4, 7, 11, 14, 21];
+pub(crate) const DOMINANT_13: &[u8] = &[0, 4, 7, 10, 14, 21];
+pub(crate) const MINOR_13: &[u8] = &[0, 3, 7, 10, 14, 21];
+
+// Modes of the major scale
+pub(crate) const IONIAN: &[u8] = &[0, 2, 4, 5, 7, 9, 11];
+pub(crate) const DORIAN: &[u8] = &[0, 2, 3, 5, 7, 9, 10];
+pub(crate) const PHRYGIAN: &[u8] = &[0, 1, 3, 5, 7, 8, 10];
+pub(crate) const LYDIAN: &[u8] = &[0, 2, 4, 6, 7, 9, 11];
+pub(crate) const MIXOLYDIAN: &[u8] = &[0, 2, 4, 5, 7, 9, 10];
+pub(crate) const AEOLIAN: &[u8] = &[0, 2, 3, 5, 7, 8, 10];
+pub(crate) const LOCRIAN: &[u8] = &[0, 1, 3, 5, 6, 8, 10];
+
+// Minor scale variants
+pub(crate) const HARMONIC_MINOR: &[u8] = &[0, 2, 3, 5, 7, 8, 11];
+pub(crate) const MELODIC_MINOR: &[u8] = &[0, 2, 3, 5, 7, 9, 11];
+
+// Key signature accidentals, in order
+pub(crate) const SHARPS: [&str; 7] = ["F#", "C#", "G#", "D#", "A#", "E#", "B#"];
+pub(crate) const FLATS: [&str; 7] = ["Bb", "Eb", "Ab", "Db", "Gb", "Cb", "Fb"];
+
+// Modes of the melodic minor scale
+pub(crate) const DORIAN_FLAT_2: &[u8] = &[0, 1, 3, 5, 7, 9, 10];
+pub(crate) const LYDIAN_AUGMENTED: &[u8] = &[0, 2, 4, 6, 8, 9, 11];
+pub(crate) const LYDIAN_DOMINANT: &[u8] = &[0, 2, 4, 6, 7, 9, 10];
+pub(crate) const MIXOLYDIAN_FLAT_6: &[u8] = &[0, 2, 4, 5, 7, 8, 10];
+pub(crate) const LOCRIAN_NATURAL_2: &[u8] = &[0, 2, 3, 5, 6, 8, 10];
+pub(crate) const ALTERED: &[u8] = &[0, 1, 3, 4, 6, 8, 10];
+
+// Other heptatonic scales
+pub(crate) const PHRYGIAN_DOMINANT: &[u8] = &[0, 1, 4, 5, 7, 8, 10];
+pub(crate) const HARMONIC_MAJOR: &[u8] = &[0, 2, 4, 5, 7, 8, 11];
+pub(crate) const DOUBLE_HARMONIC: &[u8] = &[0, 1, 4, 5, 7, 8, 11];
+
+// Symmetric scales
+pub(crate) const WHOLE_TONE: &[u8] = &[0, 2, 4, 6, 8, 10];
+pub(crate) const HALF_WHOLE: &[u8] = &[0, 1, 3, 4, 6, 7, 9, 10];
+pub(crate) const WHOLE_HALF: &[u8] = &[0, 2, 3, 5, 6, 8, 9, 11];
+pub(crate) const AUGMENTED_SCALE: &[u8] = &[0, 3, 4, 7, 8, 11];
+pub(crate) const CHROMATIC: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+// Pentatonic and hexatonic scales
+pub(crate) const MAJOR_PENTATONIC: &[u8] = &[0, 2, 4, 7, 9];
+pub(crate) const MINOR_PENTATONIC: &[u8] = &[0, 3, 5, 7, 10];
+pub(crate) const BLUES: &[u8] = &[0, 3, 5, 6, 7, 10];
+pub(crate) const MAJOR_BEBOP: &[u8] = &[0, 2, 4, 5, 7, 8, 9, 11];
+pub(crate) const DOMINANT_BEBOP: &[u8] = &[0, 2, 4, 5, 7, 9, 10, 11];
+
+/// Named scales, in the order they are preferred when several names fit.
+pub(crate) const CATALOG: &[(&str, &[u8])] = &[
+    ("major", IONIAN),
+    ("dorian", DORIAN),
+    ("phrygian", PHRYGIAN),
+    ("lydian", LYDIAN),
+    ("mixolydian", MIXOLYDIAN),
+    ("minor", AEOLIAN),
+    ("locrian", LOCRIAN),
+    ("melodic minor", MELODIC_MINOR),
+    ("dorian b2", DORIAN_FLAT_2),
+    ("lydian augmented", LYDIAN_AUGMENTED),
+    ("lydian dominant", LYDIAN_DOMINANT),
+    ("mixolydian b6", MIXOLYDIAN_FLAT_6),
+    ("locrian #2", LOCRIAN_NATURAL_2),
+    ("altered", ALTERED),
+    ("harmonic minor", HARMONIC_MINOR),
+    ("phrygian dominant", PHRYGIAN_DOMINANT),
+    ("harmonic major", HARMONIC_MAJOR),
+    ("double harmonic", DOUBLE_HARMONIC),
+    ("whole tone", WHOLE_TONE),
+    ("half-whole diminished", HALF_WHOLE),
+    ("whole-half diminished", WHOLE_HALF),
+    ("augmented", AUGMENTED_SCALE),
+    ("major pentatonic", MAJOR_PENTATONIC),
+    ("minor pentatonic", MINOR_PENTATONIC),
+    ("blues", BLUES),
+    ("major bebop", MAJOR_BEBOP),
+    ("dominant bebop", DOMINANT_BEBOP),
+    ("chromatic", CHROMATIC),
+];
@@ -0,0 +1,143 @@
+use crate::iter::DirectionalPitches;
+use crate::iter::{MatchIndices, MatchIndicesInternal, RMatchIndices};
+use crate::iter::{Matches, MatchesInternal, RMatches};
+use crate::pattern::Pattern;
+use crate::OCTAVE;
+use crate::{Pitch, Scale};
+use serde::{Deserialize, Serialize};
+
+/// A direction of melodic travel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+/// A scale with different tones going up than coming down, such as the
+/// classical melodic minor or the aroha and avaroha of a raga.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct DirectionalScale {
+    ascending: Scale,
+    descending: Scale,
+}
+
+impl DirectionalScale {
+    /// Creates a new `DirectionalScale` from its `ascending` and `descending` forms.
+    pub fn new(ascending: Scale, descending: Scale) -> DirectionalScale {
+        DirectionalScale {
+            ascending,
+            descending,
+        }
+    }
+
+    /// Creates a `DirectionalScale` with the same tones in both directions.
+    pub fn symmetric(scale: Scale) -> DirectionalScale {
+        DirectionalScale {
+            ascending: scale.clone(),
+            descending: scale,
+        }
+    }
+
+    /// The form of the scale used when travelling in `direction`.
+    #[inline]
+    pub fn scale(&self, direction: Direction) -> &Scale {
+        match direction {
+            Direction::Ascending => &self.ascending,
+            Direction::Descending => &self.descending,
+        }
+    }
+
+    /// Whether both directions use the same tones.
+    pub fn is_symmetric(&self) -> bool {
+        self.ascending == self.descending
+    }
+
+    /// The pitches of the scale above `root` in the order they are played in
+    /// `direction`. Descending pitches start from the highest tone.
+    pub fn pitches(&self, root: Pitch, direction: Direction) -> DirectionalPitches<'_> {
+        DirectionalPitches {
+            inner: self.scale(direction).pitches(root),
+            ascending: direction == Direction::Ascending,
+        }
+    }
+
+    /// The interval above the root of the zero-based `degree` in `direction`.
+    pub fn degree(&self, degree: usize, direction: Direction) -> Option<u8> {
+        self.scale(direction).get(degree).copied()
+    }
+
+    /// The zero-based degree of `interval` in `direction`, if it belongs to
+    /// that form of the scale.
+    pub fn degree_of(&self, interval: u8, direction: Direction) -> Option<usize> {
+        self.scale(direction)
+            .binary_search(&(interval % OCTAVE))
+            .ok()
+    }
+
+    /// The next scale pitch after `from` when moving in `direction`, with the
+    /// scale rooted on `root`. `from` need not belong to the scale.
+    pub fn step(&self, root: Pitch, from: Pitch, direction: Direction) -> Option<Pitch> {
+        let scale = self.scale(direction);
+        let octave = OCTAVE as i16;
+        let relative = from.as_interval() as i16 - root.as_interval() as i16;
+        let within = relative.rem_euclid(octave);
+        let base = relative - within;
+        let next = match direction {
+            Direction::Ascending => scale
+                .iter()
+                .map(|&i| i as i16)
+                .find(|&i| i > within)
+                .or_else(|| scale.first().map(|&i| i as i16 + octave)),
+            Direction::Descending => scale
+                .iter()
+                .rev()
+                .map(|&i| i as i16)
+                .find(|&i| i < within)
+                .or_else(|| scale.last().map(|&i| i as i16 - octave)),
+        }?;
+        let interval = root.as_interval() as i16 + base + next;
+        if (0..=u8::MAX as i16).contains(&interval) {
+            Some(Pitch::from_interval(interval as u8))
+        } else {
+            None
+        }
+    }
+
+    pub fn matches<'a, P>(&'a self, direction: Direction, pat: P) -> Matches<'a, P>
+    where
+        P: Pattern<'a>,
+    {
+        Matches(MatchesInternal(pat.into_searcher(self.scale(direction))))
+    }
+
+    pub fn rmatches<'a, P>(&'a self, direction: Direction, pat: P) -> RMatches<'a, P>
+    where
+        P: Pattern<'a>,
+    {
+        RMatches(MatchesInternal(pat.into_searcher(self.scale(direction))))
+    }
+
+    pub fn match_indices<'a, P>(&'a self, direction: Direction, pat: P) -> MatchIndices<'a, P>
+    where
+        P: Pattern<'a>,
+    {
+        MatchIndices(MatchIndicesInternal(
+            pat.into_searcher(self.scale(direction)),
+        ))
+    }
+
+    pub fn rmatch_indices<'a, P>(&'a self, direction: Direction, pat: P) -> RMatchIndices<'a, P>
+    where
+        P: Pattern<'a>,
+    {
+        RMatchIndices(MatchIndicesInternal(
+            pat.into_searcher(self.scale(direction)),
+        ))
+    }
+}
+
+impl From<Scale> for DirectionalScale {
+    fn from(scale: Scale) -> Self {
+        DirectionalScale::symmetric(scale)
+    }
+}
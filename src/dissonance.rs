@@ -0,0 +1,103 @@
+use crate::Pitch;
+use crate::OCTAVE;
+
+/// Amplitude falloff from one harmonic to the next.
+const FALLOFF: f64 = 0.88;
+
+/// The 5-limit just ratio of each interval within the octave.
+const RATIOS: [(u64, u64); 12] = [
+    (1, 1),
+    (16, 15),
+    (9, 8),
+    (6, 5),
+    (5, 4),
+    (4, 3),
+    (45, 32),
+    (3, 2),
+    (8, 5),
+    (5, 3),
+    (9, 5),
+    (15, 8),
+];
+
+/// The tension of each interval class, from the unison to the tritone.
+const TENSIONS: [f64; 7] = [0.0, 1.0, 0.6, 0.3, 0.2, 0.1, 0.8];
+
+/// The roughness of two sine partials after Plomp and Levelt, in the
+/// parameterisation of Sethares.
+fn partial_roughness(f1: f64, a1: f64, f2: f64, a2: f64) -> f64 {
+    let (low, high) = if f1 <= f2 { (f1, f2) } else { (f2, f1) };
+    let s = 0.24 / (0.0207 * low + 18.96);
+    let d = high - low;
+    a1.min(a2) * ((-3.5 * s * d).exp() - (-5.75 * s * d).exp())
+}
+
+/// The summed roughness between the first `partials` harmonics of each pair
+/// of `notes`, given as intervals above C(0).
+pub(crate) fn roughness(notes: &[u8], partials: usize) -> f64 {
+    let spectra: Vec<Vec<(f64, f64)>> = notes
+        .iter()
+        .map(|&n| {
+            let f = Pitch::from_interval(n).frequency();
+            (1..=partials)
+                .map(|k| (f * k as f64, FALLOFF.powi(k as i32 - 1)))
+                .collect()
+        })
+        .collect();
+    let mut total = 0.0;
+    for (i, a) in spectra.iter().enumerate() {
+        for b in &spectra[i + 1..] {
+            for &(f1, a1) in a {
+                for &(f2, a2) in b {
+                    total += partial_roughness(f1, a1, f2, a2);
+                }
+            }
+        }
+    }
+    total
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// `notes`, as intervals above C(0), heard as harmonics of a common
+/// fundamental using just ratios: the reciprocal of the highest harmonic
+/// number. A major triad, 4:5:6, scores 1/6.
+pub(crate) fn harmonicity(notes: &[u8]) -> f64 {
+    let bass = match notes.iter().min() {
+        Some(&bass) => bass,
+        None => return 1.0,
+    };
+    let ratios: Vec<(u64, u64)> = notes
+        .iter()
+        .map(|&n| {
+            let interval = n - bass;
+            let (p, q) = RATIOS[(interval % OCTAVE) as usize];
+            let p = p << (interval / OCTAVE);
+            let g = gcd(p, q);
+            (p / g, q / g)
+        })
+        .collect();
+    let denominator = ratios.iter().fold(1, |l, &(_, q)| l / gcd(l, q) * q);
+    let harmonics: Vec<u64> = ratios.iter().map(|&(p, q)| p * (denominator / q)).collect();
+    let common = harmonics.iter().fold(0, |g, &h| gcd(g, h));
+    let highest = harmonics.iter().max().map_or(1, |&h| h / common);
+    1.0 / highest as f64
+}
+
+/// The summed interval class tension of every pair of `notes`.
+pub(crate) fn tension(notes: &[u8]) -> f64 {
+    let mut total = 0.0;
+    for (i, &a) in notes.iter().enumerate() {
+        for &b in &notes[i + 1..] {
+            let class = (a as i16 - b as i16).rem_euclid(OCTAVE as i16) as usize;
+            total += TENSIONS[class.min(OCTAVE as usize - class)];
+        }
+    }
+    total
+}
@@ -34,3 +34,147 @@ impl fmt::Debug for OctaveError {
         write!(f, "{}: {}", "OctaveError", OCTERROR)
     }
 }
+
+/// The reason a string failed to parse.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub enum ParseErrorKind {
+    /// The input contained nothing to parse.
+    Empty,
+    /// The input contained an unrecognized token.
+    InvalidToken,
+    /// A step of zero semitones was given.
+    ZeroStep,
+    /// The steps did not add up to the period of the scale. Holds the sum.
+    StepSum(u32),
+    /// The input ended where more was expected.
+    UnexpectedEnd,
+}
+
+/// Error value indicating malformed input, with the byte position at which
+/// the problem was found.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+impl ParseError {
+    /// Create a new `ParseError` of `kind` found at `position`.
+    pub fn new(kind: ParseErrorKind, position: usize) -> ParseError {
+        ParseError { kind, position }
+    }
+
+    /// Extract the reason for the error
+    pub fn kind(self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Extract the byte position of the error in the input
+    pub fn position(self) -> usize {
+        self.position
+    }
+}
+
+impl Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseErrorKind::*;
+        match self.kind {
+            Empty => write!(f, "nothing to parse"),
+            InvalidToken => write!(f, "invalid token at position {}", self.position),
+            ZeroStep => write!(f, "zero step at position {}", self.position),
+            StepSum(sum) => write!(f, "steps add up to {} semitones", sum),
+            UnexpectedEnd => write!(f, "unexpected end at position {}", self.position),
+        }
+    }
+}
+
+/// Error value indicating an interval outside the period of a scale
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PeriodError {
+    interval: u8,
+    period: u8,
+}
+
+impl PeriodError {
+    /// Create a new `PeriodError` from `interval` and `period`.
+    pub fn new(interval: u8, period: u8) -> PeriodError {
+        PeriodError { interval, period }
+    }
+
+    /// Extract the overflowing interval
+    pub fn interval(self) -> u8 {
+        self.interval
+    }
+
+    /// Extract the period that was exceeded
+    pub fn period(self) -> u8 {
+        self.period
+    }
+}
+
+const PERERROR: &str = "outside period range";
+
+impl Error for PeriodError {}
+
+impl fmt::Display for PeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", PERERROR)
+    }
+}
+
+impl fmt::Debug for PeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PeriodError: {}", PERERROR)
+    }
+}
+
+/// The reason a `ChordBuilder` edit was rejected.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub enum ChordErrorKind {
+    /// The degree has no place in a chord, such as 8 or 15.
+    InvalidDegree,
+    /// The degree cannot be raised or lowered, such as the seventh.
+    InvalidAlteration,
+    /// The edit contradicts an earlier one, such as altering an omitted fifth
+    /// or asking for both a sharp and a flat eleventh.
+    Conflict,
+}
+
+/// Error value indicating an invalid chord edit, with the degree it concerned.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub struct ChordError {
+    kind: ChordErrorKind,
+    degree: u8,
+}
+
+impl ChordError {
+    /// Create a new `ChordError` of `kind` concerning `degree`.
+    pub fn new(kind: ChordErrorKind, degree: u8) -> ChordError {
+        ChordError { kind, degree }
+    }
+
+    /// Extract the reason for the error
+    pub fn kind(self) -> ChordErrorKind {
+        self.kind
+    }
+
+    /// Extract the chord degree the rejected edit concerned
+    pub fn degree(self) -> u8 {
+        self.degree
+    }
+}
+
+impl Error for ChordError {}
+
+impl fmt::Display for ChordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ChordErrorKind::*;
+        match self.kind {
+            InvalidDegree => write!(f, "{} is not a chord degree", self.degree),
+            InvalidAlteration => write!(f, "degree {} cannot be altered", self.degree),
+            Conflict => write!(f, "conflicting edits of degree {}", self.degree),
+        }
+    }
+}
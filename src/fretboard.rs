@@ -0,0 +1,283 @@
+use crate::OCTAVE;
+use crate::{Chord, Pitch};
+
+/// A way of playing a chord on a `Fretboard`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fingering {
+    /// The fret stopped on each string, in tuning order, counted from the capo.
+    /// 0 is an open string and `None` a muted one.
+    pub frets: Vec<Option<u8>>,
+    /// The sounding notes, in tuning order.
+    pub pitches: Vec<Pitch>,
+    /// The lowest sounding note.
+    pub bass: Pitch,
+    /// The position of the bass among the chord's intervals: 0 for root
+    /// position, 1 for first inversion and so on. `None` when the bass is not
+    /// a chord tone, as in a slash chord.
+    pub inversion: Option<usize>,
+    /// The chord intervals left out.
+    pub omitted: Vec<u8>,
+    /// How hard the fingering is to play; lower is easier.
+    pub score: u32,
+}
+
+/// A fretted instrument: its tuning, frets, capo and how far the hand can
+/// stretch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fretboard {
+    tuning: Vec<Pitch>,
+    frets: u8,
+    capo: u8,
+    stretch: u8,
+}
+
+impl Fretboard {
+    /// Creates a new `Fretboard` with open strings tuned to `tuning`, lowest
+    /// string first, and `frets` frets. There is no capo and the hand spans
+    /// four frets.
+    pub fn new(tuning: Vec<Pitch>, frets: u8) -> Fretboard {
+        Fretboard {
+            tuning,
+            frets,
+            capo: 0,
+            stretch: 4,
+        }
+    }
+
+    /// A six string guitar in standard tuning.
+    pub fn guitar() -> Fretboard {
+        use Pitch::*;
+        Fretboard::new(vec![E(2), A(2), D(3), G(3), B(3), E(4)], 19)
+    }
+
+    /// A four string bass in standard tuning.
+    pub fn bass() -> Fretboard {
+        use Pitch::*;
+        Fretboard::new(vec![E(1), A(1), D(2), G(2)], 20)
+    }
+
+    /// A soprano ukulele in re-entrant G–C–E–A tuning.
+    pub fn ukulele() -> Fretboard {
+        use Pitch::*;
+        Fretboard::new(vec![G(4), C(4), E(4), A(4)], 12)
+    }
+
+    /// Places a capo at `fret`.
+    pub fn with_capo(mut self, fret: u8) -> Fretboard {
+        self.capo = fret;
+        self
+    }
+
+    /// Limits fretted notes to `frets` neighbouring frets.
+    pub fn with_stretch(mut self, frets: u8) -> Fretboard {
+        self.stretch = frets;
+        self
+    }
+
+    #[inline]
+    pub fn tuning(&self) -> &[Pitch] {
+        &self.tuning
+    }
+
+    #[inline]
+    pub fn frets(&self) -> u8 {
+        self.frets
+    }
+
+    #[inline]
+    pub fn capo(&self) -> u8 {
+        self.capo
+    }
+
+    #[inline]
+    pub fn stretch(&self) -> u8 {
+        self.stretch
+    }
+
+    /// The playable fingerings of `chord` on `root`, easiest first.
+    ///
+    /// Every chord tone is sounded, except that the fifth may be left out if
+    /// `omit_fifth` is set. If `bass` is given the lowest note must be of its
+    /// pitch class, which need not be in the chord; otherwise inversions are
+    /// allowed but ranked lower.
+    pub fn fingerings(
+        &self,
+        root: Pitch,
+        chord: &Chord,
+        bass: Option<Pitch>,
+        omit_fifth: bool,
+    ) -> Vec<Fingering> {
+        let root = root.as_interval() % OCTAVE;
+        let mut tones: Vec<u8> = Vec::new();
+        for &i in chord.iter() {
+            let pc = (root + i) % OCTAVE;
+            if !tones.contains(&pc) {
+                tones.push(pc);
+            }
+        }
+        let fifth = (root + 7) % OCTAVE;
+        let optional = if omit_fifth && tones.len() > 2 && tones.contains(&fifth) {
+            Some(fifth)
+        } else {
+            None
+        };
+        let bass = bass.map(|b| b.as_interval() % OCTAVE);
+        // Any string may turn out to be the lowest sounding one, so each may
+        // play the bass.
+        let options: Vec<Vec<u8>> = self
+            .tuning
+            .iter()
+            .map(|string| {
+                (0..=self.frets.saturating_sub(self.capo))
+                    .filter(|&f| {
+                        let note = string.as_interval() as u16 + (self.capo + f) as u16;
+                        let pc = (note % OCTAVE as u16) as u8;
+                        note <= u8::MAX as u16 && (tones.contains(&pc) || bass == Some(pc))
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut search = Search {
+            board: self,
+            options: &options,
+            tones: &tones,
+            optional,
+            root,
+            bass,
+            chosen: Vec::with_capacity(self.tuning.len()),
+            found: Vec::new(),
+        };
+        search.run(0);
+        let mut found = search.found;
+        found.sort_by(|a, b| a.score.cmp(&b.score).then_with(|| a.frets.cmp(&b.frets)));
+        found
+    }
+
+    fn pitch(&self, string: usize, fret: u8) -> Pitch {
+        Pitch::from_interval(self.tuning[string].as_interval() + self.capo + fret)
+    }
+}
+
+/// A depth first search over the frets of each string.
+struct Search<'a> {
+    board: &'a Fretboard,
+    options: &'a [Vec<u8>],
+    /// The chord's pitch classes, in the order of its intervals.
+    tones: &'a [u8],
+    /// A pitch class that may be left out.
+    optional: Option<u8>,
+    root: u8,
+    bass: Option<u8>,
+    chosen: Vec<Option<u8>>,
+    found: Vec<Fingering>,
+}
+
+impl Search<'_> {
+    fn run(&mut self, string: usize) {
+        let fretted = self.chosen.iter().flatten().filter(|&&f| f > 0);
+        let low = fretted.clone().min().copied();
+        let high = fretted.max().copied();
+        if let (Some(low), Some(high)) = (low, high) {
+            if high - low >= self.board.stretch.max(1) {
+                return;
+            }
+        }
+        if string == self.options.len() {
+            if let Some(fingering) = self.fingering() {
+                self.found.push(fingering);
+            }
+            return;
+        }
+        self.chosen.push(None);
+        self.run(string + 1);
+        self.chosen.pop();
+        for &fret in &self.options[string] {
+            self.chosen.push(Some(fret));
+            self.run(string + 1);
+            self.chosen.pop();
+        }
+    }
+
+    fn fingering(&self) -> Option<Fingering> {
+        let board = self.board;
+        let sounding: Vec<(usize, Pitch)> = self
+            .chosen
+            .iter()
+            .enumerate()
+            .filter_map(|(s, f)| f.map(|f| (s, board.pitch(s, f))))
+            .collect();
+        let pcs: Vec<u8> = sounding
+            .iter()
+            .map(|(_, p)| p.as_interval() % OCTAVE)
+            .collect();
+        let omitted: Vec<u8> = self
+            .tones
+            .iter()
+            .filter(|t| !pcs.contains(t))
+            .copied()
+            .collect();
+        if omitted.iter().any(|&t| Some(t) != self.optional) {
+            return None;
+        }
+        let bass = sounding
+            .iter()
+            .map(|&(_, p)| p)
+            .min_by_key(Pitch::as_interval)?;
+        let bass_pc = bass.as_interval() % OCTAVE;
+        if self.bass.is_some_and(|b| b != bass_pc) {
+            return None;
+        }
+        // Only the bass may lie outside the chord.
+        let outside = pcs.iter().filter(|pc| !self.tones.contains(pc)).count();
+        if outside > 1 || outside == 1 && self.tones.contains(&bass_pc) {
+            return None;
+        }
+
+        let fretted: Vec<u8> = self
+            .chosen
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|&f| f > 0)
+            .collect();
+        let low = fretted.iter().min().copied().unwrap_or(0);
+        let high = fretted.iter().max().copied().unwrap_or(0);
+        // Several strings on the lowest fret are held by one finger as a barre,
+        // unless an open string lies under it.
+        let barred: Vec<usize> = (0..self.chosen.len())
+            .filter(|&s| self.chosen[s] == Some(low))
+            .collect();
+        let barre = barred.len() > 1
+            && (barred[0]..=barred[barred.len() - 1]).all(|s| self.chosen[s] != Some(0));
+        let fingers = fretted.len() - if barre { barred.len() - 1 } else { 0 };
+        if fingers > 4 {
+            return None;
+        }
+        let first = sounding.first()?.0;
+        let last = sounding.last()?.0;
+        let muted = self.chosen.len() - sounding.len();
+        let inner_muted = (first..=last).filter(|&s| self.chosen[s].is_none()).count();
+
+        let inversion = self.tones.iter().position(|&t| t == bass_pc);
+        let mut score = 2 * (high - low) as u32 + fingers as u32 + low as u32 / 2;
+        score += 4 * muted as u32 + 3 * inner_muted as u32;
+        score += 2 * omitted.len() as u32 + barre as u32;
+        // An unasked-for inversion costs more than muting two strings, so that
+        // x32010 ranks above C/E at 032010 and xx0232 above D/A at x00232.
+        if self.bass.is_none() && bass_pc != self.root {
+            score += 8;
+        }
+        let omitted = omitted
+            .iter()
+            .map(|&t| (t + OCTAVE - self.root) % OCTAVE)
+            .collect();
+        Some(Fingering {
+            frets: self.chosen.clone(),
+            pitches: sounding.iter().map(|&(_, p)| p).collect(),
+            bass,
+            inversion,
+            omitted,
+            score,
+        })
+    }
+}
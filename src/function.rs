@@ -0,0 +1,147 @@
+use crate::numeral::{diatonic, RomanNumeral};
+use crate::OCTAVE;
+use crate::{Chord, Key, Pitch, Quality};
+use std::fmt;
+
+/// The harmonic function of a chord in a key.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Function {
+    Tonic,
+    /// Subdominant, or predominant.
+    Subdominant,
+    Dominant,
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Function::Tonic => "T",
+            Function::Subdominant => "S",
+            Function::Dominant => "D",
+        })
+    }
+}
+
+/// A chord's `Function` with its Riemann label, such as "Tp" for the
+/// relative minor of the tonic in a major key. Minor chords are written in
+/// lower case.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HarmonicFunction {
+    pub function: Function,
+    pub label: &'static str,
+}
+
+/// A cadence ending on a chord of a progression.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cadence {
+    /// Dominant to tonic.
+    Authentic,
+    /// Subdominant to tonic.
+    Plagal,
+    /// A phrase left on the dominant.
+    Half,
+    /// Dominant to the submediant in place of the tonic.
+    Deceptive,
+}
+
+/// The function of a diatonic chord on `degree` in a major or minor key.
+fn diatonic_function(minor: bool, degree: u8, major_third: bool) -> HarmonicFunction {
+    use Function::*;
+    let (function, label) = match (minor, degree) {
+        (false, 1) => (Tonic, "T"),
+        (false, 2) => (Subdominant, "Sp"),
+        (false, 3) => (Dominant, "Dp"),
+        (false, 4) => (Subdominant, "S"),
+        (false, 5) => (Dominant, "D"),
+        (false, 6) => (Tonic, "Tp"),
+        (false, _) => (Dominant, "D"),
+        (true, 1) => (Tonic, "t"),
+        (true, 2) | (true, 4) => (Subdominant, "s"),
+        (true, 3) => (Tonic, "tP"),
+        (true, 5) if major_third => (Dominant, "D"),
+        (true, 5) => (Dominant, "d"),
+        (true, 6) => (Tonic, "tG"),
+        (true, _) if major_third => (Dominant, "dP"),
+        (true, _) => (Dominant, "D"),
+    };
+    HarmonicFunction { function, label }
+}
+
+/// The function of `chord` on `root` in `key`. Chords borrowed from the
+/// parallel key take their function there; secondary dominants are labelled
+/// "(D)" and the Neapolitan "sN".
+pub(crate) fn function(key: &Key, root: Pitch, chord: &Chord) -> Option<HarmonicFunction> {
+    let numeral = key.analyze(root, chord)?;
+    let major_third = numeral.quality().intervals()[1] == 4;
+    let pc = root.as_interval() % OCTAVE;
+    if numeral.secondary().is_some() {
+        return Some(HarmonicFunction {
+            function: Function::Dominant,
+            label: "(D)",
+        });
+    }
+    if let Some(degree) = diatonic(key, pc, chord) {
+        return Some(diatonic_function(
+            key.mode().is_minor(),
+            degree,
+            major_third,
+        ));
+    }
+    let parallel = key.parallel();
+    if let Some(degree) = diatonic(&parallel, pc, chord) {
+        return Some(diatonic_function(
+            parallel.mode().is_minor(),
+            degree,
+            major_third,
+        ));
+    }
+    if numeral.accidental() == -1 && numeral.degree() == 2 && numeral.quality() == Quality::Major {
+        return Some(HarmonicFunction {
+            function: Function::Subdominant,
+            label: "sN",
+        });
+    }
+    None
+}
+
+fn is_dominant(numeral: &RomanNumeral) -> bool {
+    numeral.secondary().is_none()
+        && numeral.accidental() == 0
+        && (numeral.degree() == 5 && numeral.quality().intervals()[1] == 4
+            || numeral.degree() == 7 && numeral.quality().intervals()[2] == 6)
+}
+
+fn is_degree(numeral: &RomanNumeral, degree: u8) -> bool {
+    numeral.secondary().is_none() && numeral.degree() == degree
+}
+
+/// The cadences in a progression of `(root, chord)` pairs, with the index of
+/// the chord each ends on. A half cadence can only close the progression.
+pub(crate) fn cadences(key: &Key, chords: &[(Pitch, Chord)]) -> Vec<(usize, Cadence)> {
+    let numerals: Vec<Option<RomanNumeral>> = chords
+        .iter()
+        .map(|(root, chord)| key.analyze(*root, chord))
+        .collect();
+    let mut found = Vec::new();
+    for (i, pair) in numerals.windows(2).enumerate() {
+        let (from, to) = match pair {
+            [Some(from), Some(to)] => (from, to),
+            _ => continue,
+        };
+        let tonic = is_degree(to, 1) && to.accidental() == 0;
+        if is_dominant(from) && tonic {
+            found.push((i + 1, Cadence::Authentic));
+        } else if is_degree(from, 4) && from.accidental() == 0 && tonic {
+            found.push((i + 1, Cadence::Plagal));
+        } else if is_dominant(from) && from.degree() == 5 && is_degree(to, 6) {
+            found.push((i + 1, Cadence::Deceptive));
+        }
+    }
+    // A progression left on the dominant closes with a half cadence.
+    if let Some(Some(last)) = numerals.last() {
+        if is_dominant(last) && last.degree() == 5 {
+            found.push((numerals.len() - 1, Cadence::Half));
+        }
+    }
+    found
+}
@@ -0,0 +1,112 @@
+use crate::iter::Pitches;
+use crate::{Chord, Pitch, Quality};
+
+/// The generic interval used to stack scale tones on top of each other.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stacking {
+    /// Stack adjacent scale tones.
+    Seconds,
+    /// Stack every other scale tone; triads, sevenths, ninths, etc.
+    Thirds,
+    /// Stack every third scale tone.
+    Fourths,
+    /// Stack every fourth scale tone.
+    Fifths,
+    /// Stack every `n`th scale tone. `Steps(2)` is the same as `Thirds`.
+    Steps(usize),
+}
+
+impl Stacking {
+    /// The number of scale degrees between adjacent chord tones.
+    pub fn steps(self) -> usize {
+        match self {
+            Stacking::Seconds => 1,
+            Stacking::Thirds => 2,
+            Stacking::Fourths => 3,
+            Stacking::Fifths => 4,
+            Stacking::Steps(n) => n,
+        }
+    }
+}
+
+/// A `Chord` built on one degree of a `Scale`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScaleChord {
+    /// The zero-based scale degree the chord is built on.
+    pub degree: usize,
+    /// The interval of the chord root above the scale root.
+    pub root: u8,
+    /// The chord intervals above `root`.
+    pub chord: Chord,
+    /// The named quality of `chord`, if it has one.
+    pub quality: Option<Quality>,
+}
+
+impl ScaleChord {
+    /// The quality name of the chord, or "unnamed".
+    pub fn name(&self) -> &'static str {
+        self.quality.map_or("unnamed", Quality::name)
+    }
+
+    /// The chord root when the scale is rooted on `scale_root`.
+    pub fn root_pitch(&self, scale_root: Pitch) -> Option<Pitch> {
+        scale_root.add_interval(self.root)
+    }
+
+    /// The chord pitches when the scale is rooted on `scale_root`.
+    ///
+    /// ***Panics*** if the chord root is out of pitch range.
+    pub fn pitches(&self, scale_root: Pitch) -> Pitches<'_> {
+        let root = self
+            .root_pitch(scale_root)
+            .expect("chord root out of range");
+        self.chord.pitches(root)
+    }
+}
+
+/// Build a chord of `voices` tones on every degree of `scale`, stacking tones
+/// `stacking` apart. Tones past the end of the scale wrap into the next period;
+/// the stack stops at the first tone more than 255 semitones above the root.
+pub(crate) fn harmonize(
+    scale: &[u8],
+    period: u8,
+    stacking: Stacking,
+    voices: usize,
+) -> Vec<ScaleChord> {
+    let len = scale.len();
+    let steps = stacking.steps();
+    if len == 0 || steps == 0 {
+        return Vec::new();
+    }
+    (0..len)
+        .map(|degree| {
+            let root = scale[degree];
+            let mut chord = Chord::new();
+            for voice in 1..voices {
+                let interval = voice
+                    .checked_mul(steps)
+                    .and_then(|offset| offset.checked_add(degree))
+                    .and_then(|index| {
+                        (index / len)
+                            .checked_mul(period as usize)?
+                            .checked_add(scale[index % len] as usize)
+                    })
+                    .map(|tone| tone - root as usize)
+                    .filter(|&interval| interval <= u8::MAX as usize);
+                match interval {
+                    Some(interval) => {
+                        chord.insert(interval as u8);
+                    }
+                    None => break,
+                }
+            }
+            let quality = Quality::from_chord(&chord);
+            ScaleChord {
+                degree,
+                root,
+                chord,
+                quality,
+            }
+        })
+        .collect()
+}
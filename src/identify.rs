@@ -0,0 +1,123 @@
+use crate::OCTAVE;
+use crate::{Chord, ChordSymbol, Pitch, Quality};
+
+/// One interpretation of a set of sounding pitches as a named chord.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordCandidate {
+    /// The chord root, in octave zero.
+    pub root: Pitch,
+    pub quality: Quality,
+    /// The lowest sounding note, in octave zero.
+    pub bass: Pitch,
+    /// The position of `bass` among the chord tones, in the order of
+    /// `Quality::intervals`: 0 for root position, 1 for first inversion and so
+    /// on. `None` when the bass is not a chord tone, as in a slash chord.
+    pub inversion: Option<usize>,
+    /// Whether the root is missing from the sounding pitches.
+    pub rootless: bool,
+    /// The chord tones that are not sounding, as intervals above the root.
+    pub missing: Vec<u8>,
+    /// How unlikely the interpretation is; lower scores rank first.
+    pub score: u32,
+}
+
+impl ChordCandidate {
+    /// The `Chord` of `quality`.
+    pub fn chord(&self) -> Chord {
+        self.quality.chord()
+    }
+
+    /// The chord symbol of this interpretation, written as a slash chord
+    /// whenever the bass is not the root.
+    pub fn symbol(&self) -> ChordSymbol {
+        let bass = if self.bass == self.root {
+            None
+        } else {
+            Some(self.bass)
+        };
+        ChordSymbol::new(self.root, self.chord(), bass)
+    }
+}
+
+fn pitch_class(p: Pitch) -> u8 {
+    p.as_interval() % OCTAVE
+}
+
+/// Chord tones that may be left out, as intervals above the root: the root
+/// itself, the perfect fifth, and the ninth of elevenths and thirteenths.
+fn may_omit(quality: Quality, pc: u8) -> bool {
+    let extended = quality.intervals().iter().any(|&i| i == 17 || i == 21);
+    match pc {
+        0 | 7 => true,
+        2 => extended,
+        _ => false,
+    }
+}
+
+/// Every named chord the sounding `pitches` could spell, most plausible
+/// first. Each sounding note must be a chord tone, except that the lowest note
+/// may lie outside the chord.
+pub(crate) fn identify(pitches: &[Pitch]) -> Vec<ChordCandidate> {
+    let lowest = match (0..pitches.len()).min_by_key(|&i| pitches[i].as_interval()) {
+        Some(i) => i,
+        None => return Vec::new(),
+    };
+    let bass = pitch_class(pitches[lowest]);
+    // The notes above the bass, which still include its pitch class when it
+    // is doubled higher up.
+    let upper = pitches
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != lowest)
+        .fold(0u16, |m, (_, &p)| m | 1 << pitch_class(p));
+    let sounding = upper | 1 << bass;
+
+    let mut candidates = Vec::new();
+    for root in 0..OCTAVE {
+        for &quality in Quality::ALL.iter() {
+            let tones: Vec<u8> = quality
+                .intervals()
+                .iter()
+                .map(|i| (root + i % OCTAVE) % OCTAVE)
+                .collect();
+            let mask = tones.iter().fold(0u16, |m, &t| m | 1 << t);
+            let slash = mask & 1 << bass == 0;
+            let heard = if slash { upper } else { sounding };
+            if heard & !mask != 0 || heard.count_ones() < 2 {
+                continue;
+            }
+            let missing: Vec<u8> = quality
+                .intervals()
+                .iter()
+                .copied()
+                .filter(|i| heard & 1 << ((root + i % OCTAVE) % OCTAVE) == 0)
+                .collect();
+            if missing.iter().any(|&i| !may_omit(quality, i % OCTAVE)) {
+                continue;
+            }
+            let rootless = heard & 1 << root == 0;
+            // Without its root a chord needs a seventh and three other notes
+            // to be recognisable.
+            if rootless && (tones.len() < 4 || heard.count_ones() < 3) {
+                continue;
+            }
+            let inversion = tones.iter().position(|&t| t == bass);
+            let score = 2 * missing.len() as u32
+                + 3 * rootless as u32
+                + 4 * slash as u32
+                + (inversion.unwrap_or(0) > 0) as u32
+                + tones.len() as u32;
+            candidates.push(ChordCandidate {
+                root: Pitch::from_interval(root),
+                quality,
+                bass: Pitch::from_interval(bass),
+                inversion,
+                rootless,
+                missing,
+                score,
+            });
+        }
+    }
+    candidates.sort_by_key(|c| (c.score, c.quality, pitch_class(c.root)));
+    candidates
+}
@@ -1,5 +1,7 @@
+use crate::dissonance::tension;
 use crate::iter::Pitches;
 use crate::Pitch;
+use crate::OCTAVE;
 use serde::{Deserialize, Serialize};
 use std::iter::{Extend, FromIterator};
 use std::ops::{Deref, DerefMut, RangeBounds};
@@ -121,6 +123,27 @@ impl IntervalSet {
             iter: self.iter(),
         }
     }
+
+    /// The summed tension of the interval class between every pair of
+    /// intervals, as `Voicing::tension`.
+    pub fn tension(&self) -> f64 {
+        tension(&self.set)
+    }
+
+    /// The inversion of the set about `n`: every interval `i` becomes `n - i`,
+    /// raised by octaves if `i` is above `n`.
+    pub fn invert(&self, n: u8) -> IntervalSet {
+        self.set
+            .iter()
+            .map(|&i| {
+                if i <= n {
+                    n - i
+                } else {
+                    (OCTAVE - (i - n) % OCTAVE) % OCTAVE
+                }
+            })
+            .collect()
+    }
 }
 
 impl Deref for IntervalSet {
@@ -57,6 +57,44 @@ impl<'a> DoubleEndedIterator for Pitches<'a> {
 
 impl FusedIterator for Pitches<'_> {}
 
+/// Pitches of a scale in the order they are played in one direction of travel.
+#[derive(Debug)]
+pub struct DirectionalPitches<'a> {
+    pub(super) inner: Pitches<'a>,
+    pub(super) ascending: bool,
+}
+
+impl<'a> Iterator for DirectionalPitches<'a> {
+    type Item = Pitch;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ascending {
+            self.inner.next()
+        } else {
+            self.inner.next_back()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for DirectionalPitches<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.ascending {
+            self.inner.next_back()
+        } else {
+            self.inner.next()
+        }
+    }
+}
+
+impl FusedIterator for DirectionalPitches<'_> {}
+
 pub(super) struct MatchIndicesInternal<'a, P: Pattern<'a>>(pub(super) P::Searcher);
 
 impl<'a, P> fmt::Debug for MatchIndicesInternal<'a, P>
@@ -0,0 +1,388 @@
+use crate::chord_scale::ChordScale;
+use crate::consts::*;
+use crate::function::{cadences, function, Cadence, HarmonicFunction};
+use crate::harmonize::ScaleChord;
+use crate::modulation::{modulation_paths, pivot_chords, ModulationPath, PivotChord};
+use crate::numeral::RomanNumeral;
+use crate::OCTAVE;
+use crate::{Accidental, Chord, ChordSymbol, ParseError, Pitch, Scale};
+use std::fmt;
+
+/// A mode in which a `Key` is interpreted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Mode {
+    Ionian,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Aeolian,
+    Locrian,
+    HarmonicMinor,
+    MelodicMinor,
+}
+
+impl Mode {
+    pub const MAJOR: Mode = Mode::Ionian;
+    pub const MINOR: Mode = Mode::Aeolian;
+
+    /// The intervals above the tonic of the scale of this mode.
+    pub fn intervals(self) -> &'static [u8] {
+        use Mode::*;
+        match self {
+            Ionian => IONIAN,
+            Dorian => DORIAN,
+            Phrygian => PHRYGIAN,
+            Lydian => LYDIAN,
+            Mixolydian => MIXOLYDIAN,
+            Aeolian => AEOLIAN,
+            Locrian => LOCRIAN,
+            HarmonicMinor => HARMONIC_MINOR,
+            MelodicMinor => MELODIC_MINOR,
+        }
+    }
+
+    /// The `Scale` of this mode.
+    pub fn scale(self) -> Scale {
+        let mut scale = Scale::new();
+        for &i in self.intervals() {
+            scale.insert(i);
+        }
+        scale
+    }
+
+    /// The interval of the tonic above the tonic of the major key sharing its
+    /// key signature. Minor scale variants share the signature of `Aeolian`.
+    pub fn offset(self) -> u8 {
+        use Mode::*;
+        match self {
+            Ionian => 0,
+            Dorian => 2,
+            Phrygian => 4,
+            Lydian => 5,
+            Mixolydian => 7,
+            Aeolian | HarmonicMinor | MelodicMinor => 9,
+            Locrian => 11,
+        }
+    }
+
+    /// Whether the mode has a minor third above its tonic.
+    pub fn is_minor(self) -> bool {
+        self.intervals()[2] == 3
+    }
+
+    pub fn name(self) -> &'static str {
+        use Mode::*;
+        match self {
+            Ionian => "major",
+            Dorian => "dorian",
+            Phrygian => "phrygian",
+            Lydian => "lydian",
+            Mixolydian => "mixolydian",
+            Aeolian => "minor",
+            Locrian => "locrian",
+            HarmonicMinor => "harmonic minor",
+            MelodicMinor => "melodic minor",
+        }
+    }
+}
+
+/// A key signature, stored as its position on the circle of fifths: positive
+/// for sharps, negative for flats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeySignature {
+    fifths: i8,
+}
+
+impl KeySignature {
+    /// Creates a `KeySignature` with `fifths` sharps, or flats when negative.
+    ///
+    /// ***Panics*** if there are more than seven sharps or flats.
+    pub fn new(fifths: i8) -> KeySignature {
+        assert!(
+            (-7..=7).contains(&fifths),
+            "KeySignature::new: {} is out of bounds -7..=7",
+            fifths
+        );
+        KeySignature { fifths }
+    }
+
+    /// The position on the circle of fifths; negative for flats.
+    #[inline]
+    pub fn fifths(self) -> i8 {
+        self.fifths
+    }
+
+    /// The number of sharps or flats.
+    #[inline]
+    pub fn count(self) -> u8 {
+        self.fifths.unsigned_abs()
+    }
+
+    /// Whether the signature has sharps or flats, or `None` if it is empty.
+    pub fn accidental(self) -> Option<Accidental> {
+        match self.fifths {
+            0 => None,
+            f if f > 0 => Some(Accidental::Sharp),
+            _ => Some(Accidental::Flat),
+        }
+    }
+
+    /// The sharps or flats of the signature, in the order they are written.
+    pub fn accidentals(self) -> &'static [&'static str] {
+        match self.accidental() {
+            None => &[],
+            Some(Accidental::Sharp) => &SHARPS[..self.count() as usize],
+            Some(Accidental::Flat) => &FLATS[..self.count() as usize],
+        }
+    }
+
+    /// The name of pitch class `pc` among the seven notes this signature
+    /// spells, or `None` if it is not one of them.
+    pub fn spell(self, pc: u8) -> Option<String> {
+        const LETTERS: [(char, u8); 7] = [
+            ('C', 0),
+            ('D', 2),
+            ('E', 4),
+            ('F', 5),
+            ('G', 7),
+            ('A', 9),
+            ('B', 11),
+        ];
+        let accidentals = self.accidentals();
+        LETTERS.iter().find_map(|&(letter, natural)| {
+            let altered = accidentals.iter().find(|a| a.starts_with(letter));
+            let (name, value) = match (altered, self.accidental()) {
+                (Some(a), Some(Accidental::Sharp)) => (a.to_string(), natural + 1),
+                (Some(a), Some(Accidental::Flat)) => (a.to_string(), natural + OCTAVE - 1),
+                _ => (letter.to_string(), natural),
+            };
+            if value % OCTAVE == pc % OCTAVE {
+                Some(name)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// A tonic and `Mode`; the context in which scales and chords are interpreted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    tonic: u8,
+    mode: Mode,
+    signature: KeySignature,
+}
+
+impl Key {
+    /// Creates a new `Key` on `tonic`, spelled with the fewest accidentals.
+    /// Six-accidental keys are spelled with sharps.
+    ///
+    /// The octave of `tonic` is ignored.
+    pub fn new(tonic: Pitch, mode: Mode) -> Key {
+        let fifths = Key::parent_fifths(tonic, mode);
+        let fifths = if fifths > 6 { fifths - 12 } else { fifths };
+        Key::with_fifths(tonic, mode, fifths)
+    }
+
+    /// Creates a new `Key` on `tonic`, spelled with `accidental` where the
+    /// tonic allows either spelling.
+    pub fn with_accidental(tonic: Pitch, mode: Mode, accidental: Accidental) -> Key {
+        let sharps = Key::parent_fifths(tonic, mode);
+        let fifths = match accidental {
+            Accidental::Sharp if sharps <= 7 => sharps,
+            Accidental::Flat if sharps >= 5 => sharps - 12,
+            _ if sharps > 6 => sharps - 12,
+            _ => sharps,
+        };
+        Key::with_fifths(tonic, mode, fifths)
+    }
+
+    /// Creates the key in `mode` with `signature`, e.g. three flats gives
+    /// Eb major, or C minor.
+    pub fn from_signature(signature: KeySignature, mode: Mode) -> Key {
+        let parent = (signature.fifths() as i16 * 7).rem_euclid(OCTAVE as i16) as u8;
+        let tonic = (parent + mode.offset()) % OCTAVE;
+        Key {
+            tonic,
+            mode,
+            signature,
+        }
+    }
+
+    /// Sharps in the signature of the parent major key, from 0 to 11.
+    fn parent_fifths(tonic: Pitch, mode: Mode) -> i8 {
+        let parent = (tonic.as_interval() % OCTAVE + OCTAVE - mode.offset()) % OCTAVE;
+        // Seven fifths up is one semitone up, so this inverts `fifths * 7`.
+        ((parent * 7) % OCTAVE) as i8
+    }
+
+    fn with_fifths(tonic: Pitch, mode: Mode, fifths: i8) -> Key {
+        Key {
+            tonic: tonic.as_interval() % OCTAVE,
+            mode,
+            signature: KeySignature::new(fifths),
+        }
+    }
+
+    /// The tonic, in octave zero.
+    #[inline]
+    pub fn tonic(&self) -> Pitch {
+        Pitch::from_interval(self.tonic)
+    }
+
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn signature(&self) -> KeySignature {
+        self.signature
+    }
+
+    /// The accidental used to spell chromatic notes in this key.
+    pub fn accidental(&self) -> Accidental {
+        match self.signature.accidental() {
+            Some(Accidental::Flat) => Accidental::Flat,
+            _ => Accidental::Sharp,
+        }
+    }
+
+    /// The `Scale` of the key, rooted on `tonic`.
+    pub fn scale(&self) -> Scale {
+        self.mode.scale()
+    }
+
+    /// Whether `pitch` is one of the notes of the key.
+    pub fn contains(&self, pitch: Pitch) -> bool {
+        let relative = (pitch.as_interval() % OCTAVE + OCTAVE - self.tonic) % OCTAVE;
+        self.mode.intervals().binary_search(&relative).is_ok()
+    }
+
+    /// The key's scale heard over `chord` on `root`, or `None` if the chord is
+    /// not diatonic.
+    pub fn chord_scale(&self, root: Pitch, chord: &Chord) -> Option<ChordScale> {
+        self.scale().chord_scale(self.tonic(), root, chord)
+    }
+
+    /// The diatonic triads of the key.
+    pub fn triads(&self) -> Vec<ScaleChord> {
+        self.scale().triads()
+    }
+
+    /// The diatonic seventh chords of the key.
+    pub fn sevenths(&self) -> Vec<ScaleChord> {
+        self.scale().sevenths()
+    }
+
+    /// The diatonic triads and then seventh chords with the same root and
+    /// quality in this key and `other`.
+    pub fn pivot_chords(&self, other: &Key) -> Vec<PivotChord> {
+        pivot_chords(self, other)
+    }
+
+    /// The `limit` shortest routes from this key to `other` through at most
+    /// `max_intermediate` major or minor keys, with every step sharing a pivot
+    /// triad. The shortest routes come first.
+    pub fn modulation_paths(
+        &self,
+        other: &Key,
+        max_intermediate: usize,
+        limit: usize,
+    ) -> Vec<ModulationPath> {
+        modulation_paths(self, other, max_intermediate, limit)
+    }
+
+    /// The chord named by a Roman numeral such as "V65/V" in this key.
+    pub fn numeral(&self, numeral: &str) -> Result<ChordSymbol, ParseError> {
+        numeral.parse::<RomanNumeral>().map(|n| n.resolve(self))
+    }
+
+    /// The Roman numeral of `chord` on `root` in this key.
+    pub fn analyze(&self, root: Pitch, chord: &Chord) -> Option<RomanNumeral> {
+        RomanNumeral::analyze(self, root, chord)
+    }
+
+    /// The harmonic function of `chord` on `root` in this key.
+    pub fn function(&self, root: Pitch, chord: &Chord) -> Option<HarmonicFunction> {
+        function(self, root, chord)
+    }
+
+    /// The cadences in a progression of `(root, chord)` pairs in this key,
+    /// with the index of the chord each ends on. The progression is taken as
+    /// one phrase, so only its last chord can close a half cadence.
+    pub fn cadences(&self, chords: &[(Pitch, Chord)]) -> Vec<(usize, Cadence)> {
+        cadences(self, chords)
+    }
+
+    /// The name of `pitch` in this key.
+    pub fn spell(&self, pitch: Pitch) -> String {
+        self.signature
+            .spell(pitch.as_interval() % OCTAVE)
+            .unwrap_or_else(|| pitch.to_string_with_accidental(self.accidental()))
+    }
+
+    fn transposed(&self, interval: u8, mode: Mode) -> Key {
+        let tonic = Pitch::from_interval((self.tonic + interval) % OCTAVE);
+        Key::with_accidental(tonic, mode, self.accidental())
+    }
+
+    /// The key sharing this key's signature: the minor key for a major key,
+    /// and the major key for every other mode.
+    pub fn relative(&self) -> Key {
+        let parent = (self.tonic + OCTAVE - self.mode.offset()) % OCTAVE;
+        let (tonic, mode) = match self.mode {
+            Mode::Ionian => ((parent + Mode::MINOR.offset()) % OCTAVE, Mode::MINOR),
+            _ => (parent, Mode::MAJOR),
+        };
+        Key {
+            tonic,
+            mode,
+            signature: self.signature,
+        }
+    }
+
+    /// The key on the same tonic with the opposite major or minor quality.
+    pub fn parallel(&self) -> Key {
+        let mode = if self.mode.is_minor() {
+            Mode::MAJOR
+        } else {
+            Mode::MINOR
+        };
+        self.transposed(0, mode)
+    }
+
+    /// The key a fifth above, in the same mode.
+    pub fn dominant(&self) -> Key {
+        Key::from_signature(self.neighbour(1), self.mode)
+    }
+
+    /// The key a fifth below, in the same mode.
+    pub fn subdominant(&self) -> Key {
+        Key::from_signature(self.neighbour(-1), self.mode)
+    }
+
+    fn neighbour(&self, step: i8) -> KeySignature {
+        let fifths = self.signature.fifths() + step;
+        KeySignature::new(match fifths {
+            8 => -4,
+            -8 => 4,
+            f => f,
+        })
+    }
+
+    /// The number of steps between the signatures of two keys around the circle
+    /// of fifths, from 0 to 6. Enharmonic signatures are the same distance.
+    pub fn fifths_distance(&self, other: &Key) -> u8 {
+        let d = (self.signature.fifths() as i16 - other.signature.fifths() as i16)
+            .rem_euclid(OCTAVE as i16) as u8;
+        d.min(OCTAVE - d)
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.spell(self.tonic()), self.mode.name())
+    }
+}
@@ -10,18 +10,69 @@ mod consts;
 pub mod macros;
 
 mod chord;
+mod chord_builder;
+mod chord_scale;
+mod directional_scale;
+mod dissonance;
 mod errors;
+mod fretboard;
+mod function;
+mod harmonize;
+mod identify;
 mod interval_set;
 mod iter;
+mod key;
+mod modulation;
+mod negative;
+mod neo_riemannian;
+mod numeral;
+mod periodic_scale;
+mod piano;
+mod progression;
 mod scale;
+mod scale_graph;
+mod steps;
+mod symbol;
+mod transpose;
+mod voice_leading;
+mod voicing;
 
 pub mod pattern;
 mod pitch;
+mod quality;
+mod quantize;
+mod reharmonize;
 
 pub const OCTAVE: u8 = 12;
 
 pub use crate::chord::Chord;
-pub use crate::errors::OctaveError;
+pub use crate::chord_builder::{ChordBuilder, Seventh};
+pub use crate::chord_scale::ChordScale;
+pub use crate::directional_scale::{Direction, DirectionalScale};
+pub use crate::errors::{
+    ChordError, ChordErrorKind, OctaveError, ParseError, ParseErrorKind, PeriodError,
+};
+pub use crate::fretboard::{Fingering, Fretboard};
+pub use crate::function::{Cadence, Function, HarmonicFunction};
+pub use crate::harmonize::{ScaleChord, Stacking};
+pub use crate::identify::ChordCandidate;
 pub use crate::interval_set::IntervalSet;
+pub use crate::key::{Key, KeySignature, Mode};
+pub use crate::modulation::{ModulationPath, PivotChord};
+pub use crate::negative::Axis;
+pub use crate::neo_riemannian::Transformation;
+pub use crate::numeral::RomanNumeral;
+pub use crate::periodic_scale::PeriodicScale;
+pub use crate::piano::{PianoForm, PianoVoicer, PianoVoicing};
 pub use crate::pitch::{Accidental, Pitch};
+pub use crate::progression::{Progression, ProgressionChord};
+pub use crate::quality::Quality;
+pub use crate::quantize::{Quantized, QuantizedFrequency, Quantizer, Snap};
+pub use crate::reharmonize::{Reharmonization, Reharmonizer, Rule};
 pub use crate::scale::Scale;
+pub use crate::scale_graph::{ScaleGraph, ScaleNode};
+pub use crate::steps::StepNotation;
+pub use crate::symbol::{ChordSymbol, SymbolStyle};
+pub use crate::transpose::{ChromaticPolicy, DiatonicTransposer};
+pub use crate::voice_leading::{Motion, VoiceLeader, VoiceLeading};
+pub use crate::voicing::Voicing;
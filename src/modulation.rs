@@ -0,0 +1,154 @@
+use crate::harmonize::ScaleChord;
+use crate::OCTAVE;
+use crate::{Chord, Key, Mode, Pitch, Quality};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// A diatonic chord with the same root and quality in two keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PivotChord {
+    /// The chord root, in octave zero.
+    pub root: Pitch,
+    /// The chord intervals above `root`.
+    pub chord: Chord,
+    /// The named quality of `chord`, if it has one.
+    pub quality: Option<Quality>,
+    /// The zero-based scale degree of the chord in the key being left.
+    pub from_degree: usize,
+    /// The zero-based scale degree of the chord in the key being entered.
+    pub to_degree: usize,
+}
+
+/// A route from one key to another through keys linked by pivot chords.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModulationPath {
+    /// Every key on the route, starting and ending with the keys requested.
+    pub keys: Vec<Key>,
+    /// The pivot chords available for each step between successive keys.
+    pub pivots: Vec<Vec<PivotChord>>,
+    /// The cost of the route: one per step plus the circle-of-fifths distance
+    /// covered by each step.
+    pub distance: u32,
+}
+
+fn pivots_among(
+    from: &Key,
+    to: &Key,
+    chords: Vec<ScaleChord>,
+    to_chords: &[ScaleChord],
+) -> Vec<PivotChord> {
+    let to_scale = to.scale();
+    let from_tonic = from.tonic().as_interval();
+    let to_tonic = to.tonic().as_interval();
+    chords
+        .into_iter()
+        .filter_map(|c| {
+            let root = (from_tonic + c.root) % OCTAVE;
+            let relative = (root + OCTAVE - to_tonic) % OCTAVE;
+            let to_degree = to_scale.binary_search(&relative).ok()?;
+            // The chord built on that degree of `to` must be the same chord,
+            // not just made of notes `to` happens to contain.
+            to_chords
+                .iter()
+                .find(|t| t.degree == to_degree && t.chord == c.chord)?;
+            Some(PivotChord {
+                root: Pitch::from_interval(root),
+                chord: c.chord,
+                quality: c.quality,
+                from_degree: c.degree,
+                to_degree,
+            })
+        })
+        .collect()
+}
+
+/// The diatonic triads and then seventh chords shared by `from` and `to`.
+pub(crate) fn pivot_chords(from: &Key, to: &Key) -> Vec<PivotChord> {
+    let mut pivots = triad_pivots(from, to);
+    pivots.extend(pivots_among(from, to, from.sevenths(), &to.sevenths()));
+    pivots
+}
+
+fn step_distance(from: &Key, to: &Key) -> u32 {
+    1 + from.fifths_distance(to) as u32
+}
+
+/// The `limit` cheapest routes from `from` to `to` through at most
+/// `max_intermediate` major or minor keys, where each step shares at least one
+/// pivot triad, ranked by distance and then by number of steps.
+pub(crate) fn modulation_paths(
+    from: &Key,
+    to: &Key,
+    max_intermediate: usize,
+    limit: usize,
+) -> Vec<ModulationPath> {
+    let mut keys = vec![*from, *to];
+    keys.extend(
+        (0..OCTAVE)
+            .flat_map(|t| {
+                let tonic = Pitch::from_interval(t);
+                vec![Key::new(tonic, Mode::MAJOR), Key::new(tonic, Mode::MINOR)]
+            })
+            .filter(|k| !same_key(k, from) && !same_key(k, to)),
+    );
+    // The cost of every step between two keys that share a pivot triad.
+    let steps: Vec<Vec<Option<u32>>> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            keys.iter()
+                .enumerate()
+                .map(|(j, b)| {
+                    if i == j || triad_pivots(a, b).is_empty() {
+                        None
+                    } else {
+                        Some(step_distance(a, b))
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    // Every step costs at least one, so routes are taken from the queue in
+    // order of distance and the first `limit` to reach `to` are the cheapest.
+    let mut paths = Vec::new();
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((0, 1, vec![0])));
+    while let Some(Reverse((distance, _, route))) = queue.pop() {
+        if paths.len() >= limit {
+            break;
+        }
+        let last = route[route.len() - 1];
+        if last == 1 {
+            let keys: Vec<Key> = route.iter().map(|&k| keys[k]).collect();
+            let pivots = keys
+                .windows(2)
+                .map(|w| triad_pivots(&w[0], &w[1]))
+                .collect();
+            paths.push(ModulationPath {
+                keys,
+                pivots,
+                distance,
+            });
+            continue;
+        }
+        let intermediate = route.len() - 1;
+        for (next, &cost) in steps[last].iter().enumerate() {
+            let allowed = next == 1 || intermediate < max_intermediate;
+            if let Some(cost) = cost.filter(|_| allowed && !route.contains(&next)) {
+                let mut longer = route.clone();
+                longer.push(next);
+                queue.push(Reverse((distance + cost, longer.len(), longer)));
+            }
+        }
+    }
+    paths
+}
+
+fn same_key(a: &Key, b: &Key) -> bool {
+    a.tonic() == b.tonic() && a.mode() == b.mode()
+}
+
+fn triad_pivots(from: &Key, to: &Key) -> Vec<PivotChord> {
+    pivots_among(from, to, from.triads(), &to.triads())
+}
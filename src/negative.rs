@@ -0,0 +1,78 @@
+use crate::OCTAVE;
+use crate::{Chord, IntervalSet, Key, Pitch, Scale};
+
+/// An axis pitch classes are reflected around, as in negative harmony.
+///
+/// The axis is given by two pitch classes that swap places; the axis lies
+/// midway between them. A single pitch class given twice stays in place.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Axis {
+    /// The sum of any two pitch classes that swap places, modulo the octave.
+    sum: u8,
+}
+
+impl Axis {
+    /// Creates a new `Axis` swapping `a` and `b`.
+    pub fn new(a: Pitch, b: Pitch) -> Axis {
+        Axis {
+            sum: (a.as_interval() % OCTAVE + b.as_interval() % OCTAVE) % OCTAVE,
+        }
+    }
+
+    /// The axis between the tonic and dominant of `key`, which swaps them and
+    /// turns the major third into the minor third.
+    pub fn tonic_dominant(key: &Key) -> Axis {
+        let tonic = key.tonic().as_interval() % OCTAVE;
+        Axis {
+            sum: (2 * tonic + 7) % OCTAVE,
+        }
+    }
+
+    fn reflect_pc(&self, pc: u8) -> u8 {
+        (self.sum + OCTAVE - pc % OCTAVE) % OCTAVE
+    }
+
+    /// The reflection of `pitch`, in the octave nearest to it. Ties are broken
+    /// downwards.
+    pub fn reflect(&self, pitch: Pitch) -> Pitch {
+        let note = pitch.as_interval() as i16;
+        let octave = OCTAVE as i16;
+        let pc = self.reflect_pc(pitch.as_interval()) as i16;
+        let mut reflected = note - note.rem_euclid(octave) + pc;
+        if reflected - note > octave / 2 {
+            reflected -= octave;
+        } else if note - reflected > octave / 2 {
+            reflected += octave;
+        }
+        if reflected > u8::MAX as i16 {
+            reflected -= octave;
+        } else if reflected < 0 {
+            reflected += octave;
+        }
+        Pitch::from_interval(reflected as u8)
+    }
+
+    /// Reflects every pitch of a melody or voicing in turn.
+    pub fn reflect_pitches(&self, pitches: &[Pitch]) -> Vec<Pitch> {
+        pitches.iter().map(|&p| self.reflect(p)).collect()
+    }
+
+    /// The reflection of `chord` on `root`. The stack of intervals is turned
+    /// upside down, so the chord's highest interval gives the new root, in
+    /// octave zero: G7 becomes Dm7b5 around the C–G axis.
+    pub fn reflect_chord(&self, root: Pitch, chord: &Chord) -> (Pitch, Chord) {
+        let top = chord.last().copied().unwrap_or(0);
+        let set: IntervalSet = chord.iter().collect();
+        let root = self.reflect_pc(root.as_interval() % OCTAVE + top % OCTAVE);
+        (Pitch::from_interval(root), Chord::from_set(set.invert(top)))
+    }
+
+    /// The reflection of `scale` on `root`, with its tonic reflected in octave
+    /// zero: C Ionian becomes G Phrygian, the notes of C minor, around the
+    /// C–G axis.
+    pub fn reflect_scale(&self, root: Pitch, scale: &Scale) -> (Pitch, Scale) {
+        let set: IntervalSet = scale.iter().collect();
+        let root = self.reflect_pc(root.as_interval());
+        (Pitch::from_interval(root), Scale::from_set(set.invert(0)))
+    }
+}
@@ -0,0 +1,169 @@
+use crate::OCTAVE;
+use crate::{Chord, Pitch, Quality};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// A neo-Riemannian transformation between major and minor triads.
+///
+/// Each transformation is its own inverse, so it takes major triads to minor
+/// ones and back.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Transformation {
+    /// Parallel: C to Cm, keeping the root and fifth.
+    P,
+    /// Leading-tone exchange: C to Em, keeping the third and fifth.
+    L,
+    /// Relative: C to Am, keeping the root and third.
+    R,
+    /// Slide, LPR: C to C#m, keeping the third.
+    Slide,
+    /// Nebenverwandt, RLP: C to Fm, keeping the root of the major triad.
+    N,
+    /// Hexatonic pole, LPL: C to Abm, sharing no tones.
+    H,
+}
+
+/// The pitch class of a major or minor triad's root, and whether it is
+/// minor.
+fn triad(root: Pitch, chord: &Chord) -> Option<(u8, bool)> {
+    let minor = match Quality::from_chord(chord)? {
+        Quality::Major => false,
+        Quality::Minor => true,
+        _ => return None,
+    };
+    Some((root.as_interval() % OCTAVE, minor))
+}
+
+fn as_chord(root: u8, minor: bool) -> (Pitch, Chord) {
+    let quality = if minor {
+        Quality::Minor
+    } else {
+        Quality::Major
+    };
+    (Pitch::from_interval(root), quality.chord())
+}
+
+impl Transformation {
+    pub const ALL: [Transformation; 6] = [
+        Transformation::P,
+        Transformation::L,
+        Transformation::R,
+        Transformation::Slide,
+        Transformation::N,
+        Transformation::H,
+    ];
+
+    pub fn name(self) -> &'static str {
+        use Transformation::*;
+        match self {
+            P => "P",
+            L => "L",
+            R => "R",
+            Slide => "S",
+            N => "N",
+            H => "H",
+        }
+    }
+
+    /// The semitones from the root of a major triad up to the root of the
+    /// minor triad it becomes.
+    fn offset(self) -> u8 {
+        use Transformation::*;
+        match self {
+            P => 0,
+            L => 4,
+            R => 9,
+            Slide => 1,
+            N => 5,
+            H => 8,
+        }
+    }
+
+    fn step(self, root: u8, minor: bool) -> (u8, bool) {
+        let root = if minor {
+            (root + OCTAVE - self.offset()) % OCTAVE
+        } else {
+            (root + self.offset()) % OCTAVE
+        };
+        (root, !minor)
+    }
+
+    /// The triad `chord` on `root` becomes, with its root in octave zero.
+    ///
+    /// Returns `None` if `chord` is not a major or minor triad.
+    pub fn apply(self, root: Pitch, chord: &Chord) -> Option<(Pitch, Chord)> {
+        let (root, minor) = triad(root, chord)?;
+        let (root, minor) = self.step(root, minor);
+        Some(as_chord(root, minor))
+    }
+
+    /// A shortest sequence of `using` that takes the triad `from` on
+    /// `from_root` to `to` on `to_root`. Ties go to the transformations
+    /// listed first.
+    ///
+    /// Returns `None` if either chord is not a major or minor triad or `to`
+    /// cannot be reached.
+    pub fn path(
+        from_root: Pitch,
+        from: &Chord,
+        to_root: Pitch,
+        to: &Chord,
+        using: &[Transformation],
+    ) -> Option<Vec<Transformation>> {
+        let start = triad(from_root, from)?;
+        let goal = triad(to_root, to)?;
+        let index = |(root, minor): (u8, bool)| root as usize * 2 + minor as usize;
+        let mut previous: Vec<Option<((u8, bool), Transformation)>> = vec![None; 24];
+        let mut seen = [false; 24];
+        seen[index(start)] = true;
+        let mut queue = VecDeque::from(vec![start]);
+        while let Some(current) = queue.pop_front() {
+            if current == goal {
+                let mut path = Vec::new();
+                let mut at = current;
+                while let Some((from, t)) = previous[index(at)] {
+                    path.push(t);
+                    at = from;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for &t in using {
+                let next = t.step(current.0, current.1);
+                if !seen[index(next)] {
+                    seen[index(next)] = true;
+                    previous[index(next)] = Some((current, t));
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for Transformation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The Tonnetz coordinates of the pitch class of `pitch`: steps along the
+/// axis of perfect fifths and then of major thirds from C, with the fifths
+/// in 0..4 and the thirds in 0..3.
+pub(crate) fn pitch_coordinates(pitch: Pitch) -> (i8, i8) {
+    let pc = pitch.as_interval() % OCTAVE;
+    // 4 fifths and 3 thirds cover every pitch class once.
+    (0..4)
+        .flat_map(|x| (0..3).map(move |y| (x, y)))
+        .find(|&(x, y)| (7 * x + 4 * y) as u8 % OCTAVE == pc)
+        .unwrap()
+}
+
+/// The Tonnetz coordinates of the root, third and fifth of a major or minor
+/// triad, which form a triangle around the root's coordinates.
+pub(crate) fn triad_coordinates(root: Pitch, chord: &Chord) -> Option<[(i8, i8); 3]> {
+    let (_, minor) = triad(root, chord)?;
+    let (x, y) = pitch_coordinates(root);
+    let third = if minor { (x + 1, y - 1) } else { (x, y + 1) };
+    Some([(x, y), third, (x + 1, y)])
+}
@@ -0,0 +1,445 @@
+use crate::errors::{ParseError, ParseErrorKind};
+use crate::OCTAVE;
+use crate::{Chord, ChordSymbol, Key, Mode, Pitch, Quality};
+use std::fmt;
+use std::str::FromStr;
+
+/// A chord named by the scale degree of its root in a key, e.g. "ii7",
+/// "V65/V" or "bVI".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RomanNumeral {
+    accidental: i8,
+    degree: u8,
+    quality: Quality,
+    inversion: u8,
+    secondary: Option<Box<RomanNumeral>>,
+}
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+/// The most semitones a degree can be raised or lowered, as in "bbVII".
+const MAX_ACCIDENTAL: u8 = 2;
+
+/// How a quality is written: upper case, and the marks that follow the
+/// numeral, with "7" standing for the figure.
+fn spelling(quality: Quality) -> Option<(bool, &'static str)> {
+    use Quality::*;
+    Some(match quality {
+        Major => (true, ""),
+        Minor => (false, ""),
+        Diminished => (false, "°"),
+        Augmented => (true, "+"),
+        Dominant7 => (true, "7"),
+        Major7 => (true, "M7"),
+        Minor7 => (false, "7"),
+        MinorMajor7 => (false, "M7"),
+        HalfDiminished7 => (false, "ø7"),
+        Diminished7 => (false, "°7"),
+        AugmentedMajor7 => (true, "+M7"),
+        Augmented7 => (true, "+7"),
+        Dominant9 => (true, "9"),
+        Major9 => (true, "M9"),
+        Minor9 => (false, "9"),
+        MinorMajor9 => (false, "M9"),
+        HalfDiminished9 => (false, "ø9"),
+        _ => return None,
+    })
+}
+
+/// The inversion figure of a triad or seventh chord.
+fn figure(sevenths: bool, inversion: u8) -> &'static str {
+    match (sevenths, inversion) {
+        (false, 0) => "",
+        (false, 1) => "6",
+        (false, _) => "64",
+        (true, 0) => "7",
+        (true, 1) => "65",
+        (true, 2) => "43",
+        (true, _) => "42",
+    }
+}
+
+fn degree_pc(key: &Key, degree: u8) -> u8 {
+    (key.tonic().as_interval() + key.mode().intervals()[degree as usize - 1]) % OCTAVE
+}
+
+/// The pitch class of `degree` in `key` for a chord of `quality`. In minor
+/// keys "vii" is built on the raised leading tone of harmonic minor, while
+/// "VII" stays on the subtonic.
+fn numeral_pc(key: &Key, degree: u8, quality: Quality) -> u8 {
+    let pc = degree_pc(key, degree);
+    if key.mode() == Mode::MINOR && degree == 7 && quality.intervals()[1] == 3 {
+        (pc + 1) % OCTAVE
+    } else {
+        pc
+    }
+}
+
+/// The quality of the triad a secondary chord on `degree` of `key` points
+/// at. Minor keys tonicize their major dominant.
+fn target_quality(key: &Key, degree: u8) -> Option<Quality> {
+    if key.mode() == Mode::MINOR && degree == 5 {
+        return Some(Quality::Major);
+    }
+    key.scale().triads().remove(degree as usize - 1).quality
+}
+
+/// The key a secondary numeral points at: major or harmonic minor after its
+/// third, so that its leading tone is raised.
+fn tonicized(key: &Key, numeral: &RomanNumeral) -> Key {
+    let root = numeral.root(key);
+    let mode = if numeral.quality.intervals()[1] == 3 {
+        Mode::HarmonicMinor
+    } else {
+        Mode::MAJOR
+    };
+    Key::new(root, mode)
+}
+
+impl RomanNumeral {
+    /// Creates a new `RomanNumeral` on `degree` raised by `accidental`
+    /// semitones, or lowered when negative, in `inversion`, applied to
+    /// `secondary` if given.
+    ///
+    /// Returns `None` if `degree` is not from 1 to 7, `accidental` is more than
+    /// a double sharp or flat, `quality` has no numeral spelling or the chord
+    /// has no such inversion. Ninth chords are written in root position only.
+    pub fn new(
+        accidental: i8,
+        degree: u8,
+        quality: Quality,
+        inversion: u8,
+        secondary: Option<RomanNumeral>,
+    ) -> Option<RomanNumeral> {
+        spelling(quality)?;
+        let tones = quality.intervals().len();
+        let inversions = if tones > 4 { 1 } else { tones };
+        if !(1..=7).contains(&degree)
+            || accidental.unsigned_abs() > MAX_ACCIDENTAL
+            || inversion as usize >= inversions
+        {
+            return None;
+        }
+        Some(RomanNumeral {
+            accidental,
+            degree,
+            quality,
+            inversion,
+            secondary: secondary.map(Box::new),
+        })
+    }
+
+    /// Semitones the root is raised above the scale degree, or lowered when
+    /// negative.
+    #[inline]
+    pub fn accidental(&self) -> i8 {
+        self.accidental
+    }
+
+    /// The scale degree of the root, from 1 to 7.
+    #[inline]
+    pub fn degree(&self) -> u8 {
+        self.degree
+    }
+
+    #[inline]
+    pub fn quality(&self) -> Quality {
+        self.quality
+    }
+
+    /// 0 for root position, 1 for first inversion and so on.
+    #[inline]
+    pub fn inversion(&self) -> u8 {
+        self.inversion
+    }
+
+    /// The numeral of the chord this one is applied to, as in "V/V".
+    #[inline]
+    pub fn secondary(&self) -> Option<&RomanNumeral> {
+        self.secondary.as_deref()
+    }
+
+    /// The key the numeral's degree is counted in: `key` itself, or the key of
+    /// the chord it is applied to.
+    fn local_key(&self, key: &Key) -> Key {
+        match &self.secondary {
+            Some(target) => tonicized(key, target),
+            None => *key,
+        }
+    }
+
+    /// The chord root in `key`, in octave zero.
+    pub fn root(&self, key: &Key) -> Pitch {
+        let local = self.local_key(key);
+        let pc = numeral_pc(&local, self.degree, self.quality) as i8 + self.accidental;
+        Pitch::from_interval(pc.rem_euclid(OCTAVE as i8) as u8)
+    }
+
+    /// The chord the numeral names in `key`, with its bass when inverted.
+    pub fn resolve(&self, key: &Key) -> ChordSymbol {
+        let root = self.root(key);
+        let intervals = self.quality.intervals();
+        let bass = match intervals.get(self.inversion as usize) {
+            Some(&i) if self.inversion > 0 => {
+                Some(Pitch::from_interval((root.as_interval() + i) % OCTAVE))
+            }
+            _ => None,
+        };
+        ChordSymbol::new(root, self.quality.chord(), bass)
+    }
+
+    /// The numeral of `chord` on `root` in `key`, in root position. Chords
+    /// outside the key are read, in turn, as secondary dominants or leading
+    /// tone chords, as borrowed from the parallel key, or as chromatic
+    /// chords on an altered degree.
+    ///
+    /// Returns `None` if the chord's quality has no numeral spelling.
+    pub fn analyze(key: &Key, root: Pitch, chord: &Chord) -> Option<RomanNumeral> {
+        let quality = Quality::from_chord(chord)?;
+        spelling(quality)?;
+        let root = root.as_interval() % OCTAVE;
+        let numeral = |accidental, degree| RomanNumeral {
+            accidental,
+            degree,
+            quality,
+            inversion: 0,
+            secondary: None,
+        };
+
+        if let Some(degree) = diatonic(key, root, chord) {
+            return Some(numeral(0, degree));
+        }
+
+        use Quality::*;
+        let (target, degree) = match quality {
+            Major | Dominant7 | Dominant9 => ((root + 5) % OCTAVE, 5),
+            Diminished | Diminished7 | HalfDiminished7 => ((root + 1) % OCTAVE, 7),
+            _ => (OCTAVE, 0),
+        };
+        if let Some(t) = (1..=7).find(|&d| d > 1 && degree_pc(key, d) == target) {
+            let triad = target_quality(key, t);
+            if matches!(triad, Some(Major) | Some(Minor)) {
+                let target = RomanNumeral {
+                    quality: triad.unwrap(),
+                    ..numeral(0, t)
+                };
+                let local = tonicized(key, &target);
+                if degree_pc(&local, degree) == root {
+                    return Some(RomanNumeral {
+                        secondary: Some(Box::new(target)),
+                        ..numeral(0, degree)
+                    });
+                }
+            }
+        }
+
+        let parallel = key.parallel();
+        let degree = match diatonic(&parallel, root, chord) {
+            Some(degree) => degree,
+            None => {
+                // A chromatic quality keeps the degree of its root; otherwise
+                // the root is a degree raised or lowered, but never a lowered
+                // tonic, so the chord a semitone below it is a raised seventh.
+                let near = |pc: u8| (1..=7).find(|&d| numeral_pc(key, d, quality) == pc);
+                near(root)
+                    .or_else(|| near((root + 1) % OCTAVE).filter(|&d| d != 1))
+                    .or_else(|| near((root + OCTAVE - 1) % OCTAVE))?
+            }
+        };
+        let accidental = root as i8 - numeral_pc(key, degree, quality) as i8;
+        let accidental = match accidental {
+            a if a > 6 => a - OCTAVE as i8,
+            a if a < -6 => a + OCTAVE as i8,
+            a => a,
+        };
+        Some(numeral(accidental, degree))
+    }
+}
+
+/// The degree of `root` in `key` if `chord` on it uses only notes of the key.
+/// In minor keys the dominant and leading tone chords may also take their
+/// notes from harmonic minor.
+pub(crate) fn diatonic(key: &Key, root: u8, chord: &Chord) -> Option<u8> {
+    let fits = |key: &Key| {
+        chord
+            .iter()
+            .all(|i| key.contains(Pitch::from_interval((root + i) % OCTAVE)))
+    };
+    if let Some(degree) = (1..=7).find(|&d| degree_pc(key, d) == root) {
+        if fits(key) {
+            return Some(degree);
+        }
+    }
+    if key.mode() != Mode::MINOR {
+        return None;
+    }
+    let harmonic = Key::new(key.tonic(), Mode::HarmonicMinor);
+    [5, 7]
+        .iter()
+        .copied()
+        .find(|&d| degree_pc(&harmonic, d) == root)
+        .filter(|_| fits(&harmonic))
+}
+
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (upper, marks) = spelling(self.quality).ok_or(fmt::Error)?;
+        let neapolitan = self.accidental == -1
+            && self.degree == 2
+            && self.quality == Quality::Major
+            && self.secondary.is_none();
+        let sevenths = marks.ends_with('7');
+        let marks = if sevenths {
+            marks.trim_end_matches('7')
+        } else {
+            marks
+        };
+        if neapolitan {
+            write!(f, "N")?;
+        } else {
+            for _ in 0..self.accidental.unsigned_abs() {
+                write!(f, "{}", if self.accidental < 0 { "b" } else { "#" })?;
+            }
+            let numeral = NUMERALS[self.degree as usize - 1];
+            if upper {
+                write!(f, "{}", numeral)?;
+            } else {
+                write!(f, "{}", numeral.to_lowercase())?;
+            }
+        }
+        write!(f, "{}{}", marks, figure(sevenths, self.inversion))?;
+        if let Some(target) = &self.secondary {
+            write!(f, "/{}", target)?;
+        }
+        Ok(())
+    }
+}
+
+struct Parser<'s> {
+    s: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.s[self.pos..]
+    }
+
+    fn eat(&mut self, tokens: &[&'static str]) -> Option<&'static str> {
+        let rest = self.rest();
+        let token = tokens.iter().find(|t| rest.starts_with(*t))?;
+        self.pos += token.len();
+        Some(token)
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.pos)
+    }
+
+    fn numeral(&mut self) -> Result<RomanNumeral, ParseError> {
+        if self.rest().is_empty() {
+            return Err(self.error(ParseErrorKind::UnexpectedEnd));
+        }
+        let mut accidental: i8 = 0;
+        loop {
+            let at = self.pos;
+            match self.eat(&["b", "♭", "#", "♯"]) {
+                Some("b") | Some("♭") => accidental -= 1,
+                Some(_) => accidental += 1,
+                None => break,
+            }
+            if accidental.unsigned_abs() > MAX_ACCIDENTAL {
+                return Err(ParseError::new(ParseErrorKind::InvalidToken, at));
+            }
+        }
+        let start = self.pos;
+        let (degree, upper) = if accidental == 0 && self.eat(&["N"]).is_some() {
+            accidental = -1;
+            (2, true)
+        } else {
+            const ORDER: [&str; 7] = ["VII", "III", "VI", "IV", "II", "V", "I"];
+            let upper = ORDER.iter().find(|n| self.rest().starts_with(*n));
+            let lower = ORDER
+                .iter()
+                .find(|n| self.rest().starts_with(&n.to_lowercase()));
+            let (token, upper) = match (upper, lower) {
+                (Some(n), _) => (*n, true),
+                (None, Some(n)) => (*n, false),
+                (None, None) => return Err(self.error(self.missing())),
+            };
+            self.pos += token.len();
+            let degree = NUMERALS.iter().position(|n| *n == token).unwrap() as u8 + 1;
+            (degree, upper)
+        };
+        let mark = self.eat(&["°", "o", "ø", "Ø", "+"]);
+        let major = self.eat(&["M", "maj", "Δ", "△"]).is_some();
+        let (size, inversion) = match self.eat(&["64", "65", "43", "42", "2", "6", "7", "9"]) {
+            None => (3, 0),
+            Some("6") => (3, 1),
+            Some("64") => (3, 2),
+            Some("7") => (4, 0),
+            Some("65") => (4, 1),
+            Some("43") => (4, 2),
+            Some("42") | Some("2") => (4, 3),
+            Some(_) => (5, 0),
+        };
+        use Quality::*;
+        let quality = match (size, upper, mark, major) {
+            (3, true, None, false) => Major,
+            (3, false, None, false) => Minor,
+            (3, false, Some("°"), false) | (3, false, Some("o"), false) => Diminished,
+            (3, true, Some("+"), false) => Augmented,
+            (4, true, None, false) => Dominant7,
+            (4, true, None, true) => Major7,
+            (4, false, None, false) => Minor7,
+            (4, false, None, true) => MinorMajor7,
+            (4, false, Some("ø"), false) | (4, false, Some("Ø"), false) => HalfDiminished7,
+            (4, false, Some("°"), false) | (4, false, Some("o"), false) => Diminished7,
+            (4, true, Some("+"), true) => AugmentedMajor7,
+            (4, true, Some("+"), false) => Augmented7,
+            (5, true, None, false) => Dominant9,
+            (5, true, None, true) => Major9,
+            (5, false, None, false) => Minor9,
+            (5, false, None, true) => MinorMajor9,
+            (5, false, Some("ø"), false) | (5, false, Some("Ø"), false) => HalfDiminished9,
+            _ => return Err(ParseError::new(ParseErrorKind::InvalidToken, start)),
+        };
+        let secondary = if self.eat(&["/"]).is_some() {
+            Some(Box::new(self.numeral()?))
+        } else {
+            None
+        };
+        Ok(RomanNumeral {
+            accidental,
+            degree,
+            quality,
+            inversion,
+            secondary,
+        })
+    }
+
+    fn missing(&self) -> ParseErrorKind {
+        if self.rest().is_empty() {
+            ParseErrorKind::UnexpectedEnd
+        } else {
+            ParseErrorKind::InvalidToken
+        }
+    }
+}
+
+impl FromStr for RomanNumeral {
+    type Err = ParseError;
+
+    /// Parse a numeral such as "ii7", "V7/V", "bVI", "viiø7" or "N6".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseError::new(ParseErrorKind::Empty, 0));
+        }
+        let mut parser = Parser { s, pos: 0 };
+        let numeral = parser.numeral()?;
+        if !parser.rest().is_empty() {
+            return Err(parser.error(ParseErrorKind::InvalidToken));
+        }
+        Ok(numeral)
+    }
+}
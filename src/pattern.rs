@@ -37,6 +37,14 @@ pub trait Pattern<'a>: Sized {
     }
 }
 
+/// A `Pattern` that can also be searched for in scales which repeat at a
+/// period other than the octave.
+pub trait PeriodicPattern<'a>: Pattern<'a> {
+    /// Constructs the associated searcher from `self` and a `scale`
+    /// which repeats every `period` semitones.
+    fn into_periodic_searcher(self, scale: &'a [u8], period: u8) -> Self::Searcher;
+}
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum SearchStep {
     /// Expresses that a match of the interval sequence has been found at
@@ -123,10 +131,16 @@ pub struct IntervalSearcher<'a> {
     finger_back: usize,
     /// The interval being searched for.
     interval: u8,
+    /// The interval at which the scale repeats.
+    period: u8,
 }
 
 impl<'a> IntervalSearcher<'a> {
     pub fn new(scale: &'a [u8], interval: u8) -> Self {
+        IntervalSearcher::with_period(scale, interval, OCTAVE)
+    }
+
+    pub fn with_period(scale: &'a [u8], interval: u8, period: u8) -> Self {
         let len = scale.len();
         let root_back = len;
         IntervalSearcher {
@@ -135,7 +149,8 @@ impl<'a> IntervalSearcher<'a> {
             root_back,
             finger: 0,
             finger_back: root_back + len,
-            interval: interval % OCTAVE,
+            interval: interval % period,
+            period,
         }
     }
 }
@@ -170,7 +185,9 @@ unsafe impl<'a> Searcher<'a> for IntervalSearcher<'a> {
                     self.finger
                 };
                 self.finger += old_finger_len - finger_iter.len();
-                match (f + (octave * OCTAVE as usize) as u8 - r).cmp(&self.interval) {
+                match (f as usize + octave * self.period as usize - r as usize)
+                    .cmp(&(self.interval as usize))
+                {
                     Ordering::Less => SearchStep::Reject(vec![old_root, old_finger]),
                     Ordering::Equal => {
                         self.root += old_root_len - root_iter.len();
@@ -223,7 +240,9 @@ unsafe impl<'a> ReverseSearcher<'a> for IntervalSearcher<'a> {
                 } else {
                     self.finger_back
                 };
-                match (f + (octave * OCTAVE as usize) as u8 - r).cmp(&self.interval) {
+                match (f as usize + octave * self.period as usize - r as usize)
+                    .cmp(&(self.interval as usize))
+                {
                     Ordering::Less => {
                         self.root_back -= old_root_len - root_iter.len();
                         self.finger_back = self.root_back + len;
@@ -257,16 +276,23 @@ pub struct ChordSearcher<'a, 'b> {
     root_back: usize,
     /// The chord being searched for.
     chord: &'b [u8],
+    /// The interval at which the scale repeats.
+    period: u8,
 }
 
 impl<'a, 'b> ChordSearcher<'a, 'b> {
     pub fn new(scale: &'a [u8], chord: &'b [u8]) -> Self {
+        ChordSearcher::with_period(scale, chord, OCTAVE)
+    }
+
+    pub fn with_period(scale: &'a [u8], chord: &'b [u8], period: u8) -> Self {
         let len = scale.len();
         ChordSearcher {
             scale,
             root: 0,
             root_back: len,
             chord,
+            period,
         }
     }
 }
@@ -288,7 +314,7 @@ unsafe impl<'a, 'b> Searcher<'a> for ChordSearcher<'a, 'b> {
             let mut finger = old_root;
             'chord: loop {
                 if let Some(interval) = chord_iter.next() {
-                    let interval = interval % OCTAVE;
+                    let interval = interval % self.period;
                     'interval: loop {
                         let finger_index = finger % len;
                         let octave = finger / len;
@@ -299,7 +325,9 @@ unsafe impl<'a, 'b> Searcher<'a> for ChordSearcher<'a, 'b> {
                             // finger canNOT be >= to the `len`, because it represents a scale index
                             let old_finger = if finger >= len { finger - len } else { finger };
                             finger += old_finger_len - finger_iter.len();
-                            match (f + (octave * OCTAVE as usize) as u8 - r).cmp(&interval) {
+                            match (f as usize + octave * self.period as usize - r as usize)
+                                .cmp(&(interval as usize))
+                            {
                                 Ordering::Less => continue 'interval,
                                 Ordering::Equal => {
                                     scale_indices.push(old_finger);
@@ -340,7 +368,7 @@ unsafe impl<'a, 'b> ReverseSearcher<'a> for ChordSearcher<'a, 'b> {
             let mut finger = old_root - 1;
             'chord: loop {
                 if let Some(interval) = chord_iter.next() {
-                    let interval = interval % OCTAVE;
+                    let interval = interval % self.period;
                     'interval: loop {
                         let finger_index = finger % len;
                         let octave = finger / len;
@@ -351,7 +379,9 @@ unsafe impl<'a, 'b> ReverseSearcher<'a> for ChordSearcher<'a, 'b> {
                             // finger canNOT be >= to the `len`, because it represents a scale index
                             let old_finger = if finger >= len { finger - len } else { finger };
                             finger += old_finger_len - finger_iter.len();
-                            match (f + (octave * OCTAVE as usize) as u8 - r).cmp(&interval) {
+                            match (f as usize + octave * self.period as usize - r as usize)
+                                .cmp(&(interval as usize))
+                            {
                                 Ordering::Less => continue 'interval,
                                 Ordering::Equal => {
                                     scale_indices.push(old_finger);
@@ -388,6 +418,13 @@ impl<'a> Pattern<'a> for u8 {
     }
 }
 
+impl<'a> PeriodicPattern<'a> for u8 {
+    #[inline]
+    fn into_periodic_searcher(self, scale: &'a [u8], period: u8) -> IntervalSearcher<'a> {
+        IntervalSearcher::with_period(scale, self, period)
+    }
+}
+
 impl<'a, 'b> Pattern<'a> for &'b [u8] {
     type Searcher = ChordSearcher<'a, 'b>;
 
@@ -397,6 +434,13 @@ impl<'a, 'b> Pattern<'a> for &'b [u8] {
     }
 }
 
+impl<'a, 'b> PeriodicPattern<'a> for &'b [u8] {
+    #[inline]
+    fn into_periodic_searcher(self, scale: &'a [u8], period: u8) -> ChordSearcher<'a, 'b> {
+        ChordSearcher::with_period(scale, self, period)
+    }
+}
+
 impl<'a, 'b, const N: usize> Pattern<'a> for &'b [u8; N] {
     type Searcher = ChordSearcher<'a, 'b>;
 
@@ -405,3 +449,10 @@ impl<'a, 'b, const N: usize> Pattern<'a> for &'b [u8; N] {
         ChordSearcher::new(scale, self)
     }
 }
+
+impl<'a, 'b, const N: usize> PeriodicPattern<'a> for &'b [u8; N] {
+    #[inline]
+    fn into_periodic_searcher(self, scale: &'a [u8], period: u8) -> ChordSearcher<'a, 'b> {
+        ChordSearcher::with_period(scale, self, period)
+    }
+}
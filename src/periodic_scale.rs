@@ -0,0 +1,232 @@
+use std::convert::TryFrom;
+use std::ops::{Deref, DerefMut};
+
+use crate::harmonize::{harmonize, ScaleChord, Stacking};
+use crate::iter::Pitches;
+use crate::iter::{MatchIndices, MatchIndicesInternal, RMatchIndices};
+use crate::iter::{Matches, MatchesInternal, RMatches};
+use crate::pattern::PeriodicPattern;
+use crate::steps::{format_steps, parse_steps, StepNotation};
+use crate::OCTAVE;
+use crate::{IntervalSet, ParseError, PeriodError, Pitch, Scale};
+use serde::{Deserialize, Serialize};
+
+/// A scale which repeats every `period` semitones rather than every octave.
+///
+/// The period may be shorter or longer than an octave, so scales that span
+/// several octaves before repeating can be modeled with a period of 24, and a
+/// tritave-repeating scale with a period of 19 (an octave and a fifth).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "RawPeriodicScale")]
+pub struct PeriodicScale {
+    set: IntervalSet,
+    period: u8,
+}
+
+/// The serialized form of a `PeriodicScale`, checked before use.
+#[derive(Deserialize)]
+struct RawPeriodicScale {
+    set: IntervalSet,
+    period: u8,
+}
+
+impl TryFrom<RawPeriodicScale> for PeriodicScale {
+    type Error = PeriodError;
+
+    fn try_from(raw: RawPeriodicScale) -> Result<Self, Self::Error> {
+        match raw.set.iter().find(|&&i| i >= raw.period) {
+            Some(&interval) => Err(PeriodError::new(interval, raw.period)),
+            None if raw.period == 0 => Err(PeriodError::new(0, 0)),
+            None => Ok(PeriodicScale {
+                set: raw.set,
+                period: raw.period,
+            }),
+        }
+    }
+}
+
+macro_rules! panic_oob {
+    ($method_name:expr, $interval:expr, $period:expr) => {
+        panic!(
+            concat!(
+                "PeriodicScale::",
+                $method_name,
+                ": interval {} is out of period bounds {}"
+            ),
+            $interval,
+            $period - 1
+        )
+    };
+}
+
+#[allow(dead_code)]
+impl PeriodicScale {
+    /// Creates a new `PeriodicScale` repeating every `period` semitones.
+    ///
+    /// `PeriodicScale`'s are initialized with a unison interval.
+    ///
+    /// ***Panics*** if `period` is zero.
+    pub fn new(period: u8) -> PeriodicScale {
+        assert!(period > 0, "PeriodicScale::new: period must be non-zero");
+        let mut set: IntervalSet = IntervalSet::new();
+        set.insert(0);
+        PeriodicScale { set, period }
+    }
+
+    /// Parse a `PeriodicScale` from a step pattern such as "2 2 1 2 2 2 1 2 2 1 2 2 2 1".
+    ///
+    /// Returns an error if a step is not recognized or the steps do not add up
+    /// to `period`, so a `period` of zero is always an error.
+    pub fn from_steps(steps: &str, period: u8) -> Result<PeriodicScale, ParseError> {
+        parse_steps(steps, period).map(|set| PeriodicScale { set, period })
+    }
+
+    /// Write the scale as the steps between its tones in `notation`.
+    pub fn to_steps(&self, notation: StepNotation) -> String {
+        format_steps(self, self.period, notation)
+    }
+
+    /// The interval at which the scale repeats.
+    #[inline]
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// Insert `interval` into sorted position.
+    ///
+    /// If the set did not have this `interval`, a tuple of the order index at which it
+    /// was placed and true is returned.
+    ///
+    /// If the set did have this `interval`, a tuple of the index at which it was found
+    /// and false is returned.
+    ///
+    /// ***Panics*** if the `interval` is outside period range. See `try_insert` for
+    /// fallible version.
+    pub fn insert(&mut self, interval: u8) -> (usize, bool) {
+        if interval >= self.period {
+            panic_oob!("insert", interval, self.period)
+        }
+        self.set.insert(interval)
+    }
+
+    /// Insert `interval` into sorted position.
+    ///
+    /// Returns an error if `interval` is outside period range.
+    pub fn try_insert(&mut self, interval: u8) -> Result<usize, PeriodError> {
+        if interval >= self.period {
+            Err(PeriodError::new(interval, self.period))
+        } else {
+            match self.set.find_or_insert(interval) {
+                Ok(i) | Err(i) => Ok(i),
+            }
+        }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.set.as_slice()
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.set.as_mut_slice()
+    }
+
+    /// The pitches of one period of the scale above `root`.
+    pub fn pitches(&self, root: Pitch) -> Pitches<'_> {
+        Pitches {
+            root,
+            iter: self.iter(),
+        }
+    }
+
+    /// The pitch of the `degree`th scale tone above `root`, counting on into
+    /// later periods when `degree` is past the end of the scale.
+    pub fn degree(&self, root: Pitch, degree: usize) -> Option<Pitch> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let repeat = u8::try_from(degree / len).ok()?;
+        repeat
+            .checked_mul(self.period)
+            .and_then(|offset| offset.checked_add(self[degree % len]))
+            .and_then(|interval| root.add_interval(interval))
+    }
+
+    /// Build a chord of `voices` tones on every degree of the scale by stacking
+    /// scale tones `stacking` apart.
+    pub fn harmonize(&self, stacking: Stacking, voices: usize) -> Vec<ScaleChord> {
+        harmonize(self, self.period, stacking, voices)
+    }
+
+    pub fn matches<'a, P>(&'a self, pat: P) -> Matches<'a, P>
+    where
+        P: PeriodicPattern<'a>,
+    {
+        Matches(MatchesInternal(
+            pat.into_periodic_searcher(self, self.period),
+        ))
+    }
+
+    pub fn rmatches<'a, P>(&'a self, pat: P) -> RMatches<'a, P>
+    where
+        P: PeriodicPattern<'a>,
+    {
+        RMatches(MatchesInternal(
+            pat.into_periodic_searcher(self, self.period),
+        ))
+    }
+
+    pub fn match_indices<'a, P>(&'a self, pat: P) -> MatchIndices<'a, P>
+    where
+        P: PeriodicPattern<'a>,
+    {
+        MatchIndices(MatchIndicesInternal(
+            pat.into_periodic_searcher(self, self.period),
+        ))
+    }
+
+    pub fn rmatch_indices<'a, P>(&'a self, pat: P) -> RMatchIndices<'a, P>
+    where
+        P: PeriodicPattern<'a>,
+    {
+        RMatchIndices(MatchIndicesInternal(
+            pat.into_periodic_searcher(self, self.period),
+        ))
+    }
+}
+
+impl From<Scale> for PeriodicScale {
+    fn from(scale: Scale) -> Self {
+        PeriodicScale {
+            set: scale.iter().collect(),
+            period: OCTAVE,
+        }
+    }
+}
+
+impl Deref for PeriodicScale {
+    type Target = [u8];
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for PeriodicScale {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
@@ -0,0 +1,276 @@
+use crate::OCTAVE;
+use crate::{Chord, Pitch, Voicing};
+
+/// A kind of piano voicing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PianoForm {
+    /// The root and one guide tone in the left hand, the other guide tone and
+    /// any extensions in the right. The fifth is left out.
+    Shell,
+    /// A rootless left hand voicing from the third up: 3–5–7–9, with the
+    /// thirteenth in place of the fifth on dominant chords.
+    RootlessA,
+    /// A rootless left hand voicing from the seventh up: 7–9–3–5, with the
+    /// thirteenth in place of the fifth on dominant chords.
+    RootlessB,
+    /// The third and seventh in the left hand under a major triad built on a
+    /// tension in the right. Only dominant seventh chords have these.
+    UpperStructure,
+}
+
+/// A two-handed piano voicing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PianoVoicing {
+    pub form: PianoForm,
+    /// The left hand notes, from the lowest up.
+    pub left: Vec<Pitch>,
+    /// The right hand notes, from the lowest up. Rootless forms leave the right
+    /// hand free for the melody.
+    pub right: Vec<Pitch>,
+}
+
+impl PianoVoicing {
+    /// Both hands as a single `Voicing`.
+    pub fn voicing(&self) -> Voicing {
+        let pitches: Vec<Pitch> = self.left.iter().chain(&self.right).copied().collect();
+        Voicing::new(&pitches)
+    }
+}
+
+/// The lowest note, as an interval from C(0), at which each interval up to a
+/// major seventh sounds clearly rather than muddy.
+const LOW_INTERVAL_LIMITS: [u8; 12] = [0, 40, 39, 36, 34, 33, 34, 22, 29, 29, 29, 29];
+
+/// Generates piano voicings within the reach and register of each hand.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PianoVoicer {
+    left_range: (Pitch, Pitch),
+    right_range: (Pitch, Pitch),
+    left_span: u8,
+    right_span: u8,
+}
+
+impl Default for PianoVoicer {
+    fn default() -> Self {
+        PianoVoicer::new()
+    }
+}
+
+/// The guide tones and colour of a chord, as pitch classes above its root.
+struct Tones {
+    third: u8,
+    fifth: u8,
+    seventh: u8,
+    ninth: u8,
+    dominant: bool,
+    extensions: Vec<u8>,
+}
+
+impl Tones {
+    /// Returns `None` unless the chord has a third or suspension and a seventh
+    /// or sixth.
+    fn of(chord: &Chord) -> Option<Tones> {
+        let has = |pc: u8| chord.iter().any(|i| i % OCTAVE == pc);
+        let third = [4, 3, 5].iter().copied().find(|&t| has(t))?;
+        let seventh = [10, 11, 9].iter().copied().find(|&t| has(t))?;
+        let dominant = third == 4 && seventh == 10;
+        let extensions: Vec<u8> = chord
+            .iter()
+            .filter(|&&i| i > OCTAVE)
+            .map(|i| i % OCTAVE)
+            .collect();
+        let ninth = extensions.iter().copied().find(|&e| e <= 3).unwrap_or(2);
+        let fifth = if extensions.contains(&9) || dominant && has(7) {
+            9
+        } else {
+            [7, 6, 8].iter().copied().find(|&f| has(f)).unwrap_or(7)
+        };
+        Some(Tones {
+            third,
+            fifth,
+            seventh,
+            ninth,
+            dominant,
+            extensions,
+        })
+    }
+}
+
+impl PianoVoicer {
+    /// Creates a new `PianoVoicer` with the left hand between C(2) and C(5),
+    /// the right between C(4) and C(6), and each hand spanning an octave.
+    pub fn new() -> PianoVoicer {
+        use Pitch::*;
+        PianoVoicer {
+            left_range: (C(2), C(5)),
+            right_range: (C(4), C(6)),
+            left_span: 12,
+            right_span: 12,
+        }
+    }
+
+    /// Keeps the left hand between `low` and `high`, inclusive.
+    pub fn with_left_range(mut self, low: Pitch, high: Pitch) -> PianoVoicer {
+        self.left_range = (low, high);
+        self
+    }
+
+    /// Keeps the right hand between `low` and `high`, inclusive.
+    pub fn with_right_range(mut self, low: Pitch, high: Pitch) -> PianoVoicer {
+        self.right_range = (low, high);
+        self
+    }
+
+    /// Limits the semitones between the lowest and highest note of each hand.
+    pub fn with_spans(mut self, left: u8, right: u8) -> PianoVoicer {
+        self.left_span = left;
+        self.right_span = right;
+        self
+    }
+
+    #[inline]
+    pub fn left_range(&self) -> (Pitch, Pitch) {
+        self.left_range
+    }
+
+    #[inline]
+    pub fn right_range(&self) -> (Pitch, Pitch) {
+        self.right_range
+    }
+
+    #[inline]
+    pub fn spans(&self) -> (u8, u8) {
+        (self.left_span, self.right_span)
+    }
+
+    /// Every voicing of `form` for `chord` on `root` that fits the hands and
+    /// keeps clear of muddy low intervals, from the lowest left hand up.
+    ///
+    /// Shells and rootless forms need a chord with a seventh or sixth.
+    pub fn voicings(&self, root: Pitch, chord: &Chord, form: PianoForm) -> Vec<PianoVoicing> {
+        let tones = match Tones::of(chord) {
+            Some(tones) => tones,
+            None => return Vec::new(),
+        };
+        let root = root.as_interval() % OCTAVE;
+        let pcs = |intervals: &[u8]| -> Vec<u8> {
+            intervals.iter().map(|i| (root + i) % OCTAVE).collect()
+        };
+        let mut found = Vec::new();
+        match form {
+            PianoForm::Shell => {
+                let upper: Vec<u8> = tones
+                    .extensions
+                    .iter()
+                    .copied()
+                    .filter(|&e| e != tones.third && e != tones.seventh)
+                    .collect();
+                for (left, guide) in [(tones.seventh, tones.third), (tones.third, tones.seventh)] {
+                    let mut right = vec![guide];
+                    right.extend(&upper);
+                    found.extend(self.two_hands(form, &pcs(&[0, left]), &pcs(&right)));
+                }
+            }
+            PianoForm::RootlessA | PianoForm::RootlessB => {
+                let Tones {
+                    third,
+                    fifth,
+                    seventh,
+                    ninth,
+                    ..
+                } = tones;
+                let stack = if form == PianoForm::RootlessA {
+                    [third, fifth, seventh, ninth]
+                } else {
+                    [seventh, ninth, third, fifth]
+                };
+                for left in self.place(&pcs(&stack), self.left_range, self.left_span) {
+                    found.push(PianoVoicing {
+                        form,
+                        left,
+                        right: Vec::new(),
+                    });
+                }
+            }
+            PianoForm::UpperStructure => {
+                if !tones.dominant {
+                    return found;
+                }
+                // Triads on II, bIII, bV, bVI and VI of the root.
+                for triad_root in [2, 3, 6, 8, 9] {
+                    let triad = [triad_root, triad_root + 4, triad_root + 7];
+                    for left in [[tones.third, tones.seventh], [tones.seventh, tones.third]] {
+                        found.extend(self.two_hands(form, &pcs(&left), &pcs(&triad)));
+                    }
+                }
+            }
+        }
+        found.sort_by_key(|v| {
+            let notes = |p: &[Pitch]| p.iter().map(Pitch::as_interval).collect::<Vec<u8>>();
+            (notes(&v.left), notes(&v.right))
+        });
+        found.dedup();
+        found
+    }
+
+    /// Every voicing with the pitch classes `left` and `right` stacked upwards
+    /// in each hand, the right hand above the left.
+    fn two_hands(&self, form: PianoForm, left: &[u8], right: &[u8]) -> Vec<PianoVoicing> {
+        let rights = self.place(right, self.right_range, self.right_span);
+        let mut found = Vec::new();
+        for l in self.place(left, self.left_range, self.left_span) {
+            let top = l.last().map(Pitch::as_interval);
+            for r in &rights {
+                if r[0].as_interval() > top.unwrap_or(0) {
+                    found.push(PianoVoicing {
+                        form,
+                        left: l.clone(),
+                        right: r.clone(),
+                    });
+                }
+            }
+        }
+        found
+    }
+
+    /// Every placement of the pitch classes `pcs`, each the next of its class
+    /// above the one before, within `range` and `span` and clear of muddy low
+    /// intervals.
+    fn place(&self, pcs: &[u8], range: (Pitch, Pitch), span: u8) -> Vec<Vec<Pitch>> {
+        let octave = OCTAVE as u16;
+        let (low, high) = (range.0.as_interval() as u16, range.1.as_interval() as u16);
+        let first = match pcs.first() {
+            Some(&pc) => pc as u16,
+            None => return Vec::new(),
+        };
+        let mut found = Vec::new();
+        let mut start = low + (first + octave - low % octave) % octave;
+        while start <= high {
+            let mut notes = vec![start];
+            for &pc in &pcs[1..] {
+                let above = notes[notes.len() - 1] + 1;
+                notes.push(above + (pc as u16 + octave - above % octave) % octave);
+            }
+            let top = notes[notes.len() - 1];
+            if top <= high
+                && top - start <= span as u16
+                && notes.windows(2).all(|w| clear(w[0], w[1]))
+            {
+                found.push(
+                    notes
+                        .iter()
+                        .map(|&n| Pitch::from_interval(n as u8))
+                        .collect(),
+                );
+            }
+            start += octave;
+        }
+        found
+    }
+}
+
+/// Whether the interval from `lower` up to `upper` sounds clearly.
+fn clear(lower: u16, upper: u16) -> bool {
+    let interval = upper - lower;
+    interval >= OCTAVE as u16 || lower >= LOW_INTERVAL_LIMITS[interval as usize] as u16
+}
@@ -1,6 +1,8 @@
+use crate::neo_riemannian::pitch_coordinates;
 use crate::OCTAVE;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Pitch {
     C(u8),
     CD(u8),
@@ -62,6 +64,32 @@ impl Pitch {
         }
     }
 
+    /// Create a `Pitch` from a MIDI note number, where 60 is `C(4)`.
+    ///
+    /// Returns `None` for notes below `C(0)`.
+    pub fn from_midi(note: u8) -> Option<Pitch> {
+        note.checked_sub(OCTAVE).map(Pitch::from_interval)
+    }
+
+    /// The MIDI note number of this pitch, where `C(4)` is 60.
+    ///
+    /// Returns `None` for pitches above the MIDI range.
+    pub fn as_midi(&self) -> Option<u8> {
+        self.as_interval().checked_add(OCTAVE)
+    }
+
+    /// The equal-tempered frequency of this pitch in hertz, tuned to `A(4)` at 440 Hz.
+    pub fn frequency(&self) -> f64 {
+        let midi = self.as_interval() as f64 + OCTAVE as f64;
+        440.0 * 2f64.powf((midi - 69.0) / OCTAVE as f64)
+    }
+
+    /// The Tonnetz coordinates of the pitch class: steps from C along the axis
+    /// of perfect fifths, from 0 to 3, and then of major thirds, from 0 to 2.
+    pub fn tonnetz(&self) -> (i8, i8) {
+        pitch_coordinates(*self)
+    }
+
     pub fn add_interval<T>(self, interval: T) -> Option<Pitch>
     where
         T: Into<u8>,
@@ -129,7 +157,7 @@ impl Pitch {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Accidental {
     #[allow(dead_code)]
     Flat,
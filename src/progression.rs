@@ -0,0 +1,213 @@
+use crate::numeral::diatonic;
+use crate::OCTAVE;
+use crate::{Chord, IntervalSet, Key, Pitch, RomanNumeral};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::Deref;
+
+/// A chord of a `Progression` and how long it lasts.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ProgressionChord {
+    /// The chord root, in octave zero.
+    pub root: Pitch,
+    pub chord: Chord,
+    /// The length of the chord, in whatever unit the caller counts time in,
+    /// such as beats or ticks.
+    pub duration: u32,
+}
+
+/// An ordered list of chords with durations, such as the changes of a song.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct Progression {
+    chords: Vec<ProgressionChord>,
+}
+
+/// Moves the pitch class `pc` of a note of `from` to the same degree of `to`.
+fn map_degree(from: &Key, to: &Key, pc: u8) -> u8 {
+    let from_tonic = from.tonic().as_interval() % OCTAVE;
+    let relative = (pc % OCTAVE + OCTAVE - from_tonic) % OCTAVE;
+    let degrees = from.mode().intervals();
+    let degree = degrees.iter().rposition(|&d| d <= relative).unwrap_or(0);
+    let offset = relative - degrees[degree];
+    (to.tonic().as_interval() + to.mode().intervals()[degree] + offset) % OCTAVE
+}
+
+/// Moves the root `pc` of a chord outside `from` to `to` by its numeral.
+/// Chords borrowed from the parallel key are moved to the same degree of the
+/// key with `to`'s tonic and the borrowed mode, and chords without a numeral
+/// are transposed.
+fn map_chromatic(from: &Key, to: &Key, pc: u8, chord: &Chord) -> u8 {
+    let root = Pitch::from_interval(pc);
+    let numeral = match from.analyze(root, chord) {
+        Some(numeral) => numeral,
+        None => {
+            let shift =
+                to.tonic().as_interval() % OCTAVE + OCTAVE - from.tonic().as_interval() % OCTAVE;
+            return (pc + shift) % OCTAVE;
+        }
+    };
+    let parallel = from.parallel();
+    if numeral.secondary().is_none() && diatonic(&parallel, pc, chord).is_some() {
+        let borrowed = Key::new(to.tonic(), parallel.mode());
+        return map_degree(&parallel, &borrowed, pc);
+    }
+    numeral.root(to).as_interval() % OCTAVE
+}
+
+/// Counts each window of `n` items, most frequent first and ties in order of
+/// first appearance.
+fn count_windows<T: Clone + Eq + Hash>(items: &[T], n: usize) -> Vec<(Vec<T>, usize)> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut counts: HashMap<&[T], (usize, usize)> = HashMap::new();
+    for (i, window) in items.windows(n).enumerate() {
+        counts.entry(window).or_insert((0, i)).0 += 1;
+    }
+    let mut counts: Vec<(&[T], (usize, usize))> = counts.into_iter().collect();
+    counts.sort_by_key(|&(_, (count, first))| (std::cmp::Reverse(count), first));
+    counts
+        .into_iter()
+        .map(|(window, (count, _))| (window.to_vec(), count))
+        .collect()
+}
+
+impl Progression {
+    /// Creates a new, empty `Progression`.
+    pub fn new() -> Progression {
+        Progression { chords: Vec::new() }
+    }
+
+    /// Appends `chord` on `root`, lasting `duration`.
+    pub fn push(&mut self, root: Pitch, chord: Chord, duration: u32) {
+        self.chords.push(ProgressionChord {
+            root: Pitch::from_interval(root.as_interval() % OCTAVE),
+            chord,
+            duration,
+        });
+    }
+
+    /// The summed duration of every chord.
+    pub fn duration(&self) -> u32 {
+        self.chords.iter().map(|c| c.duration).sum()
+    }
+
+    /// The `(root, chord)` pairs of the progression, as taken by
+    /// `Key::cadences` and `VoiceLeader::lead_progression`.
+    pub fn pairs(&self) -> Vec<(Pitch, Chord)> {
+        self.chords
+            .iter()
+            .map(|c| (c.root, c.chord.clone()))
+            .collect()
+    }
+
+    /// The progression with every root moved by `semitones`; negative
+    /// semitones move down.
+    pub fn transpose(&self, semitones: i8) -> Progression {
+        self.chords
+            .iter()
+            .map(|c| {
+                let pc = (c.root.as_interval() as i16 + semitones as i16).rem_euclid(OCTAVE as i16)
+                    as u8;
+                (Pitch::from_interval(pc), c.chord.clone(), c.duration)
+            })
+            .collect()
+    }
+
+    /// The progression moved from `from` to `to`, with each chord tone of a
+    /// diatonic chord moved to the same degree of the new key, so that I–IV–V
+    /// in C major becomes i–iv–v in A minor. Chords with tones outside `from`
+    /// keep their quality and are moved by their numeral, so that V7/V stays
+    /// V7/V, and ♭VII in C major, borrowed from C minor, becomes VII in A
+    /// minor.
+    pub fn change_key(&self, from: &Key, to: &Key) -> Progression {
+        self.chords
+            .iter()
+            .map(|c| {
+                let root_pc = c.root.as_interval() % OCTAVE;
+                let diatonic = c
+                    .chord
+                    .iter()
+                    .all(|i| from.contains(Pitch::from_interval((root_pc + i) % OCTAVE)));
+                if !diatonic {
+                    let new_root = map_chromatic(from, to, root_pc, &c.chord);
+                    return (Pitch::from_interval(new_root), c.chord.clone(), c.duration);
+                }
+                let new_root = map_degree(from, to, root_pc);
+                let set: IntervalSet = c
+                    .chord
+                    .iter()
+                    .map(|&i| {
+                        let pc = map_degree(from, to, root_pc + i % OCTAVE);
+                        (pc + OCTAVE - new_root) % OCTAVE + i / OCTAVE * OCTAVE
+                    })
+                    .collect();
+                (
+                    Pitch::from_interval(new_root),
+                    Chord::from_set(set),
+                    c.duration,
+                )
+            })
+            .collect()
+    }
+
+    /// Iterates over the chords with their numerals in `key`, which are
+    /// `None` where a chord cannot be named.
+    pub fn in_key<'a>(
+        &'a self,
+        key: &'a Key,
+    ) -> impl Iterator<Item = (&'a ProgressionChord, Option<RomanNumeral>)> + 'a {
+        self.chords
+            .iter()
+            .map(move |c| (c, key.analyze(c.root, &c.chord)))
+    }
+
+    /// Every run of `n` consecutive `(root, chord)` pairs with the number of
+    /// times it occurs, most frequent first. Durations are ignored.
+    pub fn ngrams(&self, n: usize) -> Vec<(Vec<(Pitch, Chord)>, usize)> {
+        count_windows(&self.pairs(), n)
+    }
+
+    /// Every run of `n` consecutive numerals in `key` with the number of times
+    /// it occurs, most frequent first. Runs including a chord that cannot be
+    /// named are skipped.
+    pub fn numeral_ngrams(&self, key: &Key, n: usize) -> Vec<(Vec<RomanNumeral>, usize)> {
+        let numerals: Vec<Option<RomanNumeral>> = self.in_key(key).map(|(_, n)| n).collect();
+        count_windows(&numerals, n)
+            .into_iter()
+            .filter_map(|(window, count)| {
+                let window: Option<Vec<RomanNumeral>> = window.into_iter().collect();
+                window.map(|w| (w, count))
+            })
+            .collect()
+    }
+}
+
+impl Deref for Progression {
+    type Target = [ProgressionChord];
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.chords
+    }
+}
+
+impl FromIterator<(Pitch, Chord, u32)> for Progression {
+    fn from_iter<I: IntoIterator<Item = (Pitch, Chord, u32)>>(iter: I) -> Self {
+        let mut progression = Progression::new();
+        for (root, chord, duration) in iter {
+            progression.push(root, chord, duration);
+        }
+        progression
+    }
+}
+
+impl<'a> IntoIterator for &'a Progression {
+    type Item = &'a ProgressionChord;
+    type IntoIter = std::slice::Iter<'a, ProgressionChord>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chords.iter()
+    }
+}
@@ -0,0 +1,208 @@
+use crate::consts::*;
+use crate::Chord;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The named quality of a chord, identified by its exact interval content.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Quality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    Sus2,
+    Sus4,
+    Power,
+    Quartal,
+    Major6,
+    Minor6,
+    SixNine,
+    Add9,
+    MinorAdd9,
+    Major7,
+    Dominant7,
+    Minor7,
+    MinorMajor7,
+    HalfDiminished7,
+    Diminished7,
+    AugmentedMajor7,
+    Augmented7,
+    Dominant7Sus4,
+    Major9,
+    Dominant9,
+    Minor9,
+    MinorMajor9,
+    HalfDiminished9,
+    AugmentedMajor9,
+    Dominant7Flat9,
+    Dominant7Sharp9,
+    Minor7Flat9,
+    HalfDiminished7Flat9,
+    Dominant11,
+    Minor11,
+    Major13,
+    Dominant13,
+    Minor13,
+}
+
+impl Quality {
+    /// Every `Quality`, ordered from simplest to most extended.
+    pub const ALL: [Quality; 37] = {
+        use Quality::*;
+        [
+            Major,
+            Minor,
+            Diminished,
+            Augmented,
+            Sus2,
+            Sus4,
+            Power,
+            Quartal,
+            Major6,
+            Minor6,
+            SixNine,
+            Add9,
+            MinorAdd9,
+            Major7,
+            Dominant7,
+            Minor7,
+            MinorMajor7,
+            HalfDiminished7,
+            Diminished7,
+            AugmentedMajor7,
+            Augmented7,
+            Dominant7Sus4,
+            Major9,
+            Dominant9,
+            Minor9,
+            MinorMajor9,
+            HalfDiminished9,
+            AugmentedMajor9,
+            Dominant7Flat9,
+            Dominant7Sharp9,
+            Minor7Flat9,
+            HalfDiminished7Flat9,
+            Dominant11,
+            Minor11,
+            Major13,
+            Dominant13,
+            Minor13,
+        ]
+    };
+
+    /// The intervals above the root that make up this quality.
+    pub fn intervals(self) -> &'static [u8] {
+        use Quality::*;
+        match self {
+            Major => MAJOR,
+            Minor => MINOR,
+            Diminished => DIMINISHED,
+            Augmented => AUGMENTED,
+            Sus2 => SUS2,
+            Sus4 => SUS4,
+            Power => POWER,
+            Quartal => QUARTAL,
+            Major6 => MAJOR_6,
+            Minor6 => MINOR_6,
+            SixNine => SIX_NINE,
+            Add9 => ADD_9,
+            MinorAdd9 => MINOR_ADD_9,
+            Major7 => MAJOR_7,
+            Dominant7 => DOMINANT_7,
+            Minor7 => MINOR_7,
+            MinorMajor7 => MINOR_MAJOR_7,
+            HalfDiminished7 => HALF_DIMINISHED_7,
+            Diminished7 => DIMINISHED_7,
+            AugmentedMajor7 => AUGMENTED_MAJOR_7,
+            Augmented7 => AUGMENTED_7,
+            Dominant7Sus4 => DOMINANT_7_SUS4,
+            Major9 => MAJOR_9,
+            Dominant9 => DOMINANT_9,
+            Minor9 => MINOR_9,
+            MinorMajor9 => MINOR_MAJOR_9,
+            HalfDiminished9 => HALF_DIMINISHED_9,
+            AugmentedMajor9 => AUGMENTED_MAJOR_9,
+            Dominant7Flat9 => DOMINANT_7_FLAT_9,
+            Dominant7Sharp9 => DOMINANT_7_SHARP_9,
+            Minor7Flat9 => MINOR_7_FLAT_9,
+            HalfDiminished7Flat9 => HALF_DIMINISHED_7_FLAT_9,
+            Dominant11 => DOMINANT_11,
+            Minor11 => MINOR_11,
+            Major13 => MAJOR_13,
+            Dominant13 => DOMINANT_13,
+            Minor13 => MINOR_13,
+        }
+    }
+
+    /// A readable name for this quality, e.g. "minor 7".
+    pub fn name(self) -> &'static str {
+        use Quality::*;
+        match self {
+            Major => "major",
+            Minor => "minor",
+            Diminished => "diminished",
+            Augmented => "augmented",
+            Sus2 => "suspended 2",
+            Sus4 => "suspended 4",
+            Power => "power",
+            Quartal => "quartal",
+            Major6 => "major 6",
+            Minor6 => "minor 6",
+            SixNine => "6/9",
+            Add9 => "add 9",
+            MinorAdd9 => "minor add 9",
+            Major7 => "major 7",
+            Dominant7 => "dominant 7",
+            Minor7 => "minor 7",
+            MinorMajor7 => "minor major 7",
+            HalfDiminished7 => "half-diminished 7",
+            Diminished7 => "diminished 7",
+            AugmentedMajor7 => "augmented major 7",
+            Augmented7 => "augmented 7",
+            Dominant7Sus4 => "dominant 7 suspended 4",
+            Major9 => "major 9",
+            Dominant9 => "dominant 9",
+            Minor9 => "minor 9",
+            MinorMajor9 => "minor major 9",
+            HalfDiminished9 => "half-diminished 9",
+            AugmentedMajor9 => "augmented major 9",
+            Dominant7Flat9 => "dominant 7 flat 9",
+            Dominant7Sharp9 => "dominant 7 sharp 9",
+            Minor7Flat9 => "minor 7 flat 9",
+            HalfDiminished7Flat9 => "half-diminished 7 flat 9",
+            Dominant11 => "dominant 11",
+            Minor11 => "minor 11",
+            Major13 => "major 13",
+            Dominant13 => "dominant 13",
+            Minor13 => "minor 13",
+        }
+    }
+
+    /// Find the `Quality` whose intervals are exactly `intervals`.
+    pub fn from_intervals(intervals: &[u8]) -> Option<Quality> {
+        Quality::ALL
+            .iter()
+            .copied()
+            .find(|q| q.intervals() == intervals)
+    }
+
+    /// Find the `Quality` of `chord`, if it is a named one.
+    pub fn from_chord(chord: &Chord) -> Option<Quality> {
+        Quality::from_intervals(chord.as_slice())
+    }
+
+    /// Build the `Chord` for this quality.
+    pub fn chord(self) -> Chord {
+        let mut chord = Chord::new();
+        for &i in self.intervals() {
+            chord.insert(i);
+        }
+        chord
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
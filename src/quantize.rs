@@ -0,0 +1,174 @@
+use crate::OCTAVE;
+use crate::{Pitch, Scale};
+
+/// How a `Quantizer` chooses between the scale tones around an input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Snap {
+    /// Snap to the closest scale tone at or above the input.
+    Up,
+    /// Snap to the closest scale tone at or below the input.
+    Down,
+    /// Snap to the closest scale tone, breaking ties upward.
+    Nearest,
+    /// Snap to the closest scale tone, breaking ties toward the previously
+    /// quantized pitch.
+    TowardPrevious,
+}
+
+/// A pitch snapped onto a scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quantized {
+    /// The scale pitch the input was moved to.
+    pub pitch: Pitch,
+    /// How far the input moved, in semitones.
+    pub offset: i8,
+}
+
+/// A frequency snapped onto a scale.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct QuantizedFrequency {
+    /// The scale pitch the input was moved to.
+    pub pitch: Pitch,
+    /// The equal-tempered frequency of `pitch` in hertz.
+    pub frequency: f64,
+    /// How far the input moved, in cents.
+    pub offset: f64,
+}
+
+/// Snaps pitches, MIDI notes and frequencies to the nearest member of a
+/// `Scale` rooted on a given pitch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Quantizer {
+    scale: Scale,
+    root: Pitch,
+    snap: Snap,
+    previous: Option<Pitch>,
+}
+
+impl Quantizer {
+    /// Creates a new `Quantizer` for `scale` rooted on `root`.
+    pub fn new(scale: Scale, root: Pitch, snap: Snap) -> Quantizer {
+        Quantizer {
+            scale,
+            root,
+            snap,
+            previous: None,
+        }
+    }
+
+    #[inline]
+    pub fn scale(&self) -> &Scale {
+        &self.scale
+    }
+
+    #[inline]
+    pub fn root(&self) -> Pitch {
+        self.root
+    }
+
+    #[inline]
+    pub fn snap(&self) -> Snap {
+        self.snap
+    }
+
+    /// The last pitch this quantizer produced.
+    #[inline]
+    pub fn previous(&self) -> Option<Pitch> {
+        self.previous
+    }
+
+    /// Forget the previously quantized pitch.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+
+    /// Whether `interval` belongs to the scale.
+    fn contains(&self, interval: i16) -> bool {
+        let octave = OCTAVE as i16;
+        let relative = (interval - self.root.as_interval() as i16).rem_euclid(octave);
+        self.scale.binary_search(&(relative as u8)).is_ok()
+    }
+
+    /// The closest scale tones at or below and at or above `x`.
+    fn neighbours(&self, x: f64) -> (Option<i16>, Option<i16>) {
+        let in_range = |i: &i16| (0..=u8::MAX as i16).contains(i);
+        let below = (0..=OCTAVE as i16)
+            .map(|d| x.floor() as i16 - d)
+            .take_while(in_range)
+            .find(|&i| self.contains(i));
+        let above = (0..=OCTAVE as i16)
+            .map(|d| x.ceil() as i16 + d)
+            .take_while(in_range)
+            .find(|&i| self.contains(i));
+        (below, above)
+    }
+
+    fn choose(&self, x: f64) -> Option<i16> {
+        let (below, above) = self.neighbours(x);
+        let (below, above) = match (self.snap, below, above) {
+            (Snap::Up, _, above) => return above,
+            (Snap::Down, below, _) => return below,
+            (_, Some(below), Some(above)) => (below, above),
+            (_, below, above) => return below.or(above),
+        };
+        let down = x - below as f64;
+        let up = above as f64 - x;
+        if down < up {
+            Some(below)
+        } else if up < down {
+            Some(above)
+        } else {
+            match (self.snap, self.previous) {
+                (Snap::TowardPrevious, Some(p)) if (p.as_interval() as f64) < x => Some(below),
+                _ => Some(above),
+            }
+        }
+    }
+
+    fn settle(&mut self, interval: i16) -> Pitch {
+        let pitch = Pitch::from_interval(interval as u8);
+        self.previous = Some(pitch);
+        pitch
+    }
+
+    /// Snap `pitch` onto the scale.
+    ///
+    /// Returns `None` if no scale tone is in range in the snapping direction.
+    pub fn quantize(&mut self, pitch: Pitch) -> Option<Quantized> {
+        let input = pitch.as_interval() as i16;
+        let output = self.choose(input as f64)?;
+        Some(Quantized {
+            pitch: self.settle(output),
+            offset: (output - input) as i8,
+        })
+    }
+
+    /// Snap a MIDI note number onto the scale. See `Pitch::from_midi`.
+    pub fn quantize_midi(&mut self, note: u8) -> Option<Quantized> {
+        Pitch::from_midi(note).and_then(|pitch| self.quantize(pitch))
+    }
+
+    /// Snap a frequency in hertz onto the scale. See `Pitch::frequency`.
+    pub fn quantize_frequency(&mut self, frequency: f64) -> Option<QuantizedFrequency> {
+        if !(frequency.is_finite() && frequency > 0.0) {
+            return None;
+        }
+        let a4 = Pitch::A(4).as_interval() as f64;
+        let input = a4 + OCTAVE as f64 * (frequency / 440.0).log2();
+        let output = self.choose(input)?;
+        let pitch = self.settle(output);
+        Some(QuantizedFrequency {
+            pitch,
+            frequency: pitch.frequency(),
+            offset: (output as f64 - input) * 100.0,
+        })
+    }
+
+    /// Snap every pitch of `pitches` onto the scale in order.
+    pub fn quantize_all<I>(&mut self, pitches: I) -> Vec<Option<Quantized>>
+    where
+        I: IntoIterator<Item = Pitch>,
+    {
+        pitches.into_iter().map(|p| self.quantize(p)).collect()
+    }
+}
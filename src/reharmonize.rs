@@ -0,0 +1,303 @@
+use crate::numeral::diatonic;
+use crate::OCTAVE;
+use crate::{Chord, ChordSymbol, Key, Pitch, Progression, ProgressionChord, Quality, SymbolStyle};
+use std::fmt;
+
+/// A way of changing the chords of a progression.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rule {
+    /// Replace a dominant chord with the dominant a tritone away.
+    TritoneSubstitution,
+    /// Replace the chord before another with the dominant seventh of it.
+    SecondaryDominant,
+    /// Replace the chord before another with the ii7–V7 of it.
+    TwoFive,
+    /// Fill a whole step between roots with a diminished seventh chord.
+    DiminishedPassing,
+    /// Replace a diatonic chord with the chord on its degree in the parallel
+    /// key.
+    ModalInterchange,
+    /// Replace a major chord with its relative minor.
+    RelativeMinor,
+}
+
+impl Rule {
+    pub const ALL: [Rule; 6] = [
+        Rule::TritoneSubstitution,
+        Rule::SecondaryDominant,
+        Rule::TwoFive,
+        Rule::DiminishedPassing,
+        Rule::ModalInterchange,
+        Rule::RelativeMinor,
+    ];
+
+    pub fn name(self) -> &'static str {
+        use Rule::*;
+        match self {
+            TritoneSubstitution => "tritone substitution",
+            SecondaryDominant => "secondary dominant",
+            TwoFive => "ii–V approach",
+            DiminishedPassing => "diminished passing chord",
+            ModalInterchange => "modal interchange",
+            RelativeMinor => "relative minor substitution",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A progression produced by applying one `Rule` once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reharmonization {
+    pub rule: Rule,
+    /// The index of the first chord of the original progression that was
+    /// changed.
+    pub index: usize,
+    pub progression: Progression,
+    /// What was changed, e.g. "Db7 replaces G7 a tritone away".
+    pub explanation: String,
+}
+
+/// Suggests reharmonizations of progressions in a key.
+///
+/// Each suggestion applies a single rule at a single place, so that they can
+/// be picked from and combined.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reharmonizer {
+    key: Key,
+    rules: Vec<Rule>,
+}
+
+impl Reharmonizer {
+    /// Creates a new `Reharmonizer` for progressions in `key`, with every rule
+    /// enabled.
+    pub fn new(key: Key) -> Reharmonizer {
+        Reharmonizer {
+            key,
+            rules: Rule::ALL.to_vec(),
+        }
+    }
+
+    /// Enables only `rules`, which are applied in the order given.
+    pub fn with_rules(mut self, rules: &[Rule]) -> Reharmonizer {
+        self.rules = rules.to_vec();
+        self
+    }
+
+    /// Disables `rule`.
+    pub fn without(mut self, rule: Rule) -> Reharmonizer {
+        self.rules.retain(|&r| r != rule);
+        self
+    }
+
+    #[inline]
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    #[inline]
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Every reharmonization of `progression` by the enabled rules, grouped by
+    /// rule and then in the order of the chords changed.
+    pub fn reharmonize(&self, progression: &Progression) -> Vec<Reharmonization> {
+        let mut found = Vec::new();
+        for &rule in &self.rules {
+            for i in 0..progression.len() {
+                if let Some((index, progression, explanation)) = self.apply(rule, progression, i) {
+                    found.push(Reharmonization {
+                        rule,
+                        index,
+                        progression,
+                        explanation,
+                    });
+                }
+            }
+        }
+        found
+    }
+
+    /// Applies `rule` around the chord at `i`, returning the index of the
+    /// first chord changed.
+    fn apply(&self, rule: Rule, p: &Progression, i: usize) -> Option<(usize, Progression, String)> {
+        let current = &p[i];
+        let root = current.root.as_interval() % OCTAVE;
+        let chord = &current.chord;
+        match rule {
+            Rule::TritoneSubstitution => {
+                if !is_dominant(chord) {
+                    return None;
+                }
+                let sub = Pitch::from_interval((root + 6) % OCTAVE);
+                let replacement = vec![(sub, chord.clone(), current.duration)];
+                let explanation = format!(
+                    "{} replaces {} a tritone away",
+                    self.name(sub, chord),
+                    self.name(current.root, chord)
+                );
+                Some((i, splice(p, i, replacement), explanation))
+            }
+            Rule::SecondaryDominant => {
+                let before = &p[i.checked_sub(1)?];
+                stable_third(chord)?;
+                let dominant = Pitch::from_interval((root + 7) % OCTAVE);
+                if before.root == dominant && is_dominant(&before.chord) {
+                    return None;
+                }
+                let seventh = Quality::Dominant7.chord();
+                let explanation = format!(
+                    "{}{} leads into {} in place of {}",
+                    self.name(dominant, &seventh),
+                    numeral(&self.key, dominant, &seventh),
+                    self.name(current.root, chord),
+                    self.name(before.root, &before.chord)
+                );
+                let replacement = vec![(dominant, seventh, before.duration)];
+                Some((i - 1, splice(p, i - 1, replacement), explanation))
+            }
+            Rule::TwoFive => {
+                let before = &p[i.checked_sub(1)?];
+                if before.duration < 2 {
+                    return None;
+                }
+                let two_quality = match stable_third(chord)? {
+                    4 => Quality::Minor7,
+                    _ => Quality::HalfDiminished7,
+                };
+                let two = Pitch::from_interval((root + 2) % OCTAVE);
+                let five = Pitch::from_interval((root + 7) % OCTAVE);
+                if before.root == two || before.root == five && is_dominant(&before.chord) {
+                    return None;
+                }
+                let (two_chord, five_chord) = (two_quality.chord(), Quality::Dominant7.chord());
+                let explanation = format!(
+                    "{} {} approaches {} in place of {}",
+                    self.name(two, &two_chord),
+                    self.name(five, &five_chord),
+                    self.name(current.root, chord),
+                    self.name(before.root, &before.chord)
+                );
+                let half = before.duration / 2;
+                let replacement = vec![
+                    (two, two_chord, before.duration - half),
+                    (five, five_chord, half),
+                ];
+                Some((i - 1, splice(p, i - 1, replacement), explanation))
+            }
+            Rule::DiminishedPassing => {
+                let next = p.get(i + 1)?;
+                let next_root = next.root.as_interval() % OCTAVE;
+                if (next_root + OCTAVE - root) % OCTAVE != 2 || current.duration < 2 {
+                    return None;
+                }
+                let passing = Pitch::from_interval((root + 1) % OCTAVE);
+                let diminished = Quality::Diminished7.chord();
+                let explanation = format!(
+                    "{} passes between {} and {}",
+                    self.name(passing, &diminished),
+                    self.name(current.root, chord),
+                    self.name(next.root, &next.chord)
+                );
+                let half = current.duration / 2;
+                let replacement = vec![
+                    (current.root, chord.clone(), current.duration - half),
+                    (passing, diminished, half),
+                ];
+                Some((i, splice(p, i, replacement), explanation))
+            }
+            Rule::ModalInterchange => {
+                let degree = diatonic(&self.key, root, chord)? as usize;
+                let parallel = self.key.parallel();
+                let chords = if chord.len() > 3 {
+                    parallel.sevenths()
+                } else {
+                    parallel.triads()
+                };
+                let borrowed = chords.into_iter().nth(degree - 1)?;
+                let borrowed_root =
+                    Pitch::from_interval((parallel.tonic().as_interval() + borrowed.root) % OCTAVE);
+                if borrowed_root == current.root && &borrowed.chord == chord {
+                    return None;
+                }
+                let explanation = format!(
+                    "{}{} is borrowed from {} in place of {}",
+                    self.name(borrowed_root, &borrowed.chord),
+                    numeral(&parallel, borrowed_root, &borrowed.chord),
+                    parallel,
+                    self.name(current.root, chord)
+                );
+                let replacement = vec![(borrowed_root, borrowed.chord, current.duration)];
+                Some((i, splice(p, i, replacement), explanation))
+            }
+            Rule::RelativeMinor => {
+                let minor = match Quality::from_chord(chord)? {
+                    Quality::Major => Quality::Minor,
+                    Quality::Major7 => Quality::Minor7,
+                    _ => return None,
+                }
+                .chord();
+                let relative = Pitch::from_interval((root + 9) % OCTAVE);
+                let explanation = format!(
+                    "{} replaces its relative major {}",
+                    self.name(relative, &minor),
+                    self.name(current.root, chord)
+                );
+                let replacement = vec![(relative, minor, current.duration)];
+                Some((i, splice(p, i, replacement), explanation))
+            }
+        }
+    }
+
+    /// The chord symbol of `chord` on `root`, spelled for the key.
+    fn name(&self, root: Pitch, chord: &Chord) -> String {
+        let symbol = ChordSymbol::new(root, chord.clone(), None);
+        match self.key.signature().accidental() {
+            Some(accidental) => symbol.format_with_accidental(SymbolStyle::Pop, accidental),
+            None => symbol.format(SymbolStyle::Pop),
+        }
+    }
+}
+
+/// The numeral of `chord` on `root` in `key` in parentheses, if it has one.
+fn numeral(key: &Key, root: Pitch, chord: &Chord) -> String {
+    key.analyze(root, chord)
+        .map(|n| format!(" ({})", n))
+        .unwrap_or_default()
+}
+
+/// Whether `chord` has a major third and minor seventh.
+fn is_dominant(chord: &Chord) -> bool {
+    let has = |pc: u8| chord.iter().any(|i| i % OCTAVE == pc);
+    has(4) && has(10)
+}
+
+/// The third of `chord` if it is a major or minor chord with a perfect fifth,
+/// which can be approached by its dominant.
+fn stable_third(chord: &Chord) -> Option<u8> {
+    let has = |pc: u8| chord.iter().any(|i| i % OCTAVE == pc);
+    if !has(7) {
+        return None;
+    }
+    match (has(3), has(4)) {
+        (true, false) => Some(3),
+        (false, true) => Some(4),
+        _ => None,
+    }
+}
+
+/// `p` with the chord at `index` replaced by `replacement`.
+fn splice(p: &Progression, index: usize, replacement: Vec<(Pitch, Chord, u32)>) -> Progression {
+    let chord = |c: &ProgressionChord| (c.root, c.chord.clone(), c.duration);
+    p[..index]
+        .iter()
+        .map(chord)
+        .chain(replacement)
+        .chain(p[index + 1..].iter().map(chord))
+        .collect()
+}
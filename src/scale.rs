@@ -1,11 +1,16 @@
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
+use crate::chord_scale::{chord_scale, ChordScale};
+use crate::consts::CATALOG;
+use crate::harmonize::{harmonize, ScaleChord, Stacking};
 use crate::iter::Pitches;
 use crate::iter::{MatchIndices, MatchIndicesInternal, RMatchIndices};
 use crate::iter::{Matches, MatchesInternal, RMatches};
 use crate::pattern::Pattern;
+use crate::steps::{format_steps, parse_steps, StepNotation};
 use crate::OCTAVE;
-use crate::{IntervalSet, OctaveError, Pitch};
+use crate::{Chord, IntervalSet, OctaveError, ParseError, Pitch};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
@@ -37,12 +42,55 @@ impl Scale {
         Scale { set }
     }
 
+    pub(crate) fn from_set(set: IntervalSet) -> Scale {
+        Scale { set }
+    }
+
     pub fn chromatic() -> Scale {
         Scale {
             set: (0..OCTAVE).collect(),
         }
     }
 
+    /// Parse a `Scale` from a step pattern such as "W W H W W W H",
+    /// "2 2 1 2 2 2 1" or "TTSTTTS".
+    ///
+    /// Returns an error if a step is not recognized or the steps do not add up
+    /// to an octave.
+    pub fn from_steps(steps: &str) -> Result<Scale, ParseError> {
+        parse_steps(steps, OCTAVE).map(|set| Scale { set })
+    }
+
+    /// Write the scale as the steps between its tones in `notation`.
+    pub fn to_steps(&self, notation: StepNotation) -> String {
+        format_steps(self, OCTAVE, notation)
+    }
+
+    /// The named scales known to the crate, e.g. ("dorian", `Scale`).
+    pub fn catalog() -> impl Iterator<Item = (&'static str, Scale)> {
+        CATALOG.iter().map(|&(name, intervals)| {
+            (
+                name,
+                Scale {
+                    set: IntervalSet::from(intervals),
+                },
+            )
+        })
+    }
+
+    /// Find a catalogued scale by `name`.
+    pub fn named(name: &str) -> Option<Scale> {
+        Scale::catalog().find(|(n, _)| *n == name).map(|(_, s)| s)
+    }
+
+    /// The catalogue name of this scale, if it has one.
+    pub fn name(&self) -> Option<&'static str> {
+        CATALOG
+            .iter()
+            .find(|(_, intervals)| *intervals == self.as_slice())
+            .map(|(name, _)| *name)
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.set.len()
@@ -113,6 +161,59 @@ impl Scale {
         }
     }
 
+    /// The pitch classes, in octave zero, shared by this scale rooted on `root`
+    /// and `other` rooted on `other_root`.
+    pub fn common_tones(&self, root: Pitch, other: &Scale, other_root: Pitch) -> Vec<Pitch> {
+        let pcs = |scale: &Scale, root: Pitch| -> Vec<u8> {
+            let root = root.as_interval() % OCTAVE;
+            let mut pcs: Vec<u8> = scale.iter().map(|i| (root + i) % OCTAVE).collect();
+            pcs.sort_unstable();
+            pcs
+        };
+        let theirs = pcs(other, other_root);
+        pcs(self, root)
+            .into_iter()
+            .filter(|pc| theirs.binary_search(pc).is_ok())
+            .map(Pitch::from_interval)
+            .collect()
+    }
+
+    /// Build a chord of `voices` tones on every degree of the scale by stacking
+    /// scale tones `stacking` apart.
+    pub fn harmonize(&self, stacking: Stacking, voices: usize) -> Vec<ScaleChord> {
+        harmonize(self, OCTAVE, stacking, voices)
+    }
+
+    /// The scale rooted on `scale_root` heard over `chord` on `root`, or `None`
+    /// if the chord is not contained in it.
+    pub fn chord_scale(&self, scale_root: Pitch, root: Pitch, chord: &Chord) -> Option<ChordScale> {
+        let offset =
+            (scale_root.as_interval() % OCTAVE + OCTAVE - root.as_interval() % OCTAVE) % OCTAVE;
+        if self.binary_search(&((OCTAVE - offset) % OCTAVE)).is_err() {
+            return None;
+        }
+        let mut rotated = Scale::new();
+        for &i in self.iter() {
+            rotated.insert((offset + i) % OCTAVE);
+        }
+        chord_scale(root, chord, rotated)
+    }
+
+    /// The diatonic triads of the scale.
+    pub fn triads(&self) -> Vec<ScaleChord> {
+        self.harmonize(Stacking::Thirds, 3)
+    }
+
+    /// The diatonic seventh chords of the scale.
+    pub fn sevenths(&self) -> Vec<ScaleChord> {
+        self.harmonize(Stacking::Thirds, 4)
+    }
+
+    /// The diatonic ninth chords of the scale.
+    pub fn ninths(&self) -> Vec<ScaleChord> {
+        self.harmonize(Stacking::Thirds, 5)
+    }
+
     pub fn matches<'a, P>(&'a self, pat: P) -> Matches<'a, P>
     where
         P: Pattern<'a>,
@@ -142,6 +243,14 @@ impl Scale {
     }
 }
 
+impl FromStr for Scale {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scale::from_steps(s)
+    }
+}
+
 impl Deref for Scale {
     type Target = [u8];
     #[inline]
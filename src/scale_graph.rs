@@ -0,0 +1,188 @@
+use crate::OCTAVE;
+use crate::{Pitch, Scale};
+use std::collections::VecDeque;
+
+/// The pitch classes of `scale` rooted on `root`, as a bit set.
+fn mask(root: Pitch, scale: &[u8]) -> u16 {
+    let root = root.as_interval() % OCTAVE;
+    scale
+        .iter()
+        .fold(0, |m, &i| m | 1 << ((root + i % OCTAVE) % OCTAVE))
+}
+
+fn pitch_classes(mask: u16) -> Vec<u8> {
+    (0..OCTAVE).filter(|pc| mask & 1 << pc != 0).collect()
+}
+
+/// The least total number of semitones the notes of one set must move to
+/// become the other, keeping their circular order. Both sets must be the
+/// same size.
+fn displacement(a: &[u8], b: &[u8]) -> u32 {
+    let n = a.len();
+    (0..n)
+        .map(|k| {
+            (0..n)
+                .map(|i| {
+                    let d = (a[i] as i16 - b[(i + k) % n] as i16).rem_euclid(OCTAVE as i16);
+                    d.min(OCTAVE as i16 - d) as u32
+                })
+                .sum()
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+/// A rooted scale in a `ScaleGraph`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScaleNode {
+    /// The catalogue name of the scale.
+    pub name: &'static str,
+    /// The root of the scale, in octave zero.
+    pub root: Pitch,
+    /// The scale intervals above `root`.
+    pub scale: Scale,
+    mask: u16,
+}
+
+impl ScaleNode {
+    /// The pitch classes of the scale, from C.
+    pub fn pitch_classes(&self) -> Vec<u8> {
+        pitch_classes(self.mask)
+    }
+}
+
+/// A graph of rooted scales in which two scales are neighbours when one can be
+/// turned into the other by moving a single note a semitone.
+///
+/// Scales holding the same pitch classes, such as C major and A minor, share a
+/// node named after whichever was added first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleGraph {
+    nodes: Vec<ScaleNode>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl ScaleGraph {
+    /// Creates a `ScaleGraph` of every transposition of the catalogued scales.
+    pub fn new() -> ScaleGraph {
+        ScaleGraph::from_scales(Scale::catalog())
+    }
+
+    /// Creates a `ScaleGraph` of every transposition of `scales`.
+    pub fn from_scales<I>(scales: I) -> ScaleGraph
+    where
+        I: IntoIterator<Item = (&'static str, Scale)>,
+    {
+        let mut nodes: Vec<ScaleNode> = Vec::new();
+        for (name, scale) in scales {
+            for root in (0..OCTAVE).map(Pitch::from_interval) {
+                let mask = mask(root, &scale);
+                if nodes.iter().all(|n| n.mask != mask) {
+                    nodes.push(ScaleNode {
+                        name,
+                        root,
+                        scale: scale.clone(),
+                        mask,
+                    });
+                }
+            }
+        }
+        let edges = nodes
+            .iter()
+            .map(|a| {
+                nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, b)| is_semitone_move(a.mask, b.mask))
+                    .map(|(j, _)| j)
+                    .collect()
+            })
+            .collect();
+        ScaleGraph { nodes, edges }
+    }
+
+    #[inline]
+    pub fn nodes(&self) -> &[ScaleNode] {
+        &self.nodes
+    }
+
+    /// The index of the node holding `scale` rooted on `root`.
+    pub fn find(&self, root: Pitch, scale: &Scale) -> Option<usize> {
+        let mask = mask(root, scale);
+        self.nodes.iter().position(|n| n.mask == mask)
+    }
+
+    /// The neighbours of the node at `index`.
+    pub fn neighbours(&self, index: usize) -> impl Iterator<Item = &ScaleNode> {
+        self.edges[index].iter().map(move |&j| &self.nodes[j])
+    }
+
+    /// The fewest semitone moves leading from one rooted scale to another,
+    /// including both ends. Returns `None` if either scale is not in the graph
+    /// or they are not connected.
+    pub fn shortest_path(
+        &self,
+        from: (Pitch, &Scale),
+        to: (Pitch, &Scale),
+    ) -> Option<Vec<&ScaleNode>> {
+        let start = self.find(from.0, from.1)?;
+        let goal = self.find(to.0, to.1)?;
+        let mut previous = vec![None; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        previous[start] = Some(start);
+        queue.push_back(start);
+        while let Some(i) = queue.pop_front() {
+            if i == goal {
+                let mut path = vec![&self.nodes[goal]];
+                let mut j = goal;
+                while j != start {
+                    j = previous[j].unwrap();
+                    path.push(&self.nodes[j]);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for &j in &self.edges[i] {
+                if previous[j].is_none() {
+                    previous[j] = Some(i);
+                    queue.push_back(j);
+                }
+            }
+        }
+        None
+    }
+
+    /// The `n` scales in the graph with the same number of notes as `scale`
+    /// rooted on `root` that need the fewest semitones of movement to reach,
+    /// paired with that movement. The scale itself is left out.
+    pub fn nearest(&self, root: Pitch, scale: &Scale, n: usize) -> Vec<(&ScaleNode, u32)> {
+        let mask = mask(root, scale);
+        let pcs = pitch_classes(mask);
+        let mut found: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|node| node.mask != mask && node.mask.count_ones() as usize == pcs.len())
+            .map(|node| (node, displacement(&pcs, &node.pitch_classes())))
+            .collect();
+        found.sort_by_key(|&(_, d)| d);
+        found.truncate(n);
+        found
+    }
+}
+
+impl Default for ScaleGraph {
+    fn default() -> Self {
+        ScaleGraph::new()
+    }
+}
+
+fn is_semitone_move(a: u16, b: u16) -> bool {
+    let gone = a & !b;
+    let added = b & !a;
+    if gone.count_ones() != 1 || added.count_ones() != 1 {
+        return false;
+    }
+    let (g, n) = (gone.trailing_zeros(), added.trailing_zeros());
+    let d = (g as i16 - n as i16).rem_euclid(OCTAVE as i16);
+    d == 1 || d == OCTAVE as i16 - 1
+}
@@ -0,0 +1,108 @@
+use crate::errors::{ParseError, ParseErrorKind};
+use crate::IntervalSet;
+
+/// Notations for writing a scale as the sequence of steps between its tones.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StepNotation {
+    /// Half, whole and augmented steps, e.g. "W W H W W W H".
+    Letters,
+    /// Semitone counts, e.g. "2 2 1 2 2 2 1".
+    Numbers,
+    /// Tones and semitones, e.g. "TTSTTTS".
+    Tones,
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == '-' || c == ','
+}
+
+fn parse_token(token: &str, position: usize) -> Result<u8, ParseError> {
+    let step = match token {
+        "H" | "h" | "S" | "s" => 1,
+        "W" | "w" | "T" | "t" => 2,
+        "A" | "a" => 3,
+        _ if token.bytes().all(|b| b.is_ascii_digit()) => token
+            .parse()
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidToken, position))?,
+        _ => return Err(ParseError::new(ParseErrorKind::InvalidToken, position)),
+    };
+    if step == 0 {
+        Err(ParseError::new(ParseErrorKind::ZeroStep, position))
+    } else {
+        Ok(step)
+    }
+}
+
+/// Parse a step pattern into the intervals of a scale repeating every `period`
+/// semitones.
+///
+/// Steps are separated by whitespace, '-' or ','. Without any separator every
+/// character is read as its own step, so "TTSTTTS" and "2212221" are accepted.
+pub(crate) fn parse_steps(s: &str, period: u8) -> Result<IntervalSet, ParseError> {
+    let mut tokens: Vec<(usize, &str)> = Vec::new();
+    if s.contains(is_separator) {
+        let mut start = None;
+        for (i, c) in s.char_indices() {
+            match (is_separator(c), start) {
+                (true, Some(st)) => {
+                    tokens.push((st, &s[st..i]));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        if let Some(st) = start {
+            tokens.push((st, &s[st..]));
+        }
+    } else {
+        tokens.extend(s.char_indices().map(|(i, c)| (i, &s[i..i + c.len_utf8()])));
+    }
+    if tokens.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0));
+    }
+
+    let mut set = IntervalSet::with_capacity(tokens.len());
+    let mut sum: u32 = 0;
+    for (position, token) in tokens {
+        if sum < period as u32 {
+            set.insert(sum as u8);
+        }
+        sum += parse_token(token, position)? as u32;
+    }
+    if sum != period as u32 {
+        return Err(ParseError::new(ParseErrorKind::StepSum(sum), s.len()));
+    }
+    Ok(set)
+}
+
+/// Write the steps between successive `intervals`, closing on `period`.
+///
+/// Steps without a letter in `notation` cause the whole pattern to be written
+/// in semitone numbers.
+pub(crate) fn format_steps(intervals: &[u8], period: u8, notation: StepNotation) -> String {
+    let steps: Vec<u8> = intervals
+        .iter()
+        .zip(intervals.iter().skip(1).chain(Some(&period)))
+        .map(|(a, b)| b - a)
+        .collect();
+    let letter = |step: u8| match (notation, step) {
+        (StepNotation::Letters, 1) => Some("H"),
+        (StepNotation::Letters, 2) => Some("W"),
+        (StepNotation::Tones, 1) => Some("S"),
+        (StepNotation::Tones, 2) => Some("T"),
+        (StepNotation::Letters, 3) | (StepNotation::Tones, 3) => Some("A"),
+        _ => None,
+    };
+    if !steps.iter().all(|&s| letter(s).is_some()) {
+        // Mixing letters and numbers reads poorly, so fall back to numbers.
+        let tokens: Vec<String> = steps.iter().map(u8::to_string).collect();
+        return tokens.join(" ");
+    }
+    let tokens: Vec<&str> = steps.iter().filter_map(|&s| letter(s)).collect();
+    tokens.join(if notation == StepNotation::Tones {
+        ""
+    } else {
+        " "
+    })
+}
@@ -0,0 +1,705 @@
+use crate::errors::{ParseError, ParseErrorKind};
+use crate::iter::Pitches;
+use crate::OCTAVE;
+use crate::{Accidental, Chord, Pitch};
+use std::fmt;
+use std::str::FromStr;
+
+/// A lead-sheet chord symbol: a root, the chord built on it and an optional
+/// bass note, e.g. "Cmaj7#11/E".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordSymbol {
+    /// The chord root, in octave zero.
+    pub root: Pitch,
+    /// The chord intervals above `root`.
+    pub chord: Chord,
+    /// The bass note of a slash chord, in octave zero.
+    pub bass: Option<Pitch>,
+}
+
+impl ChordSymbol {
+    /// Creates a new `ChordSymbol`. The octaves of `root` and `bass` are ignored.
+    pub fn new(root: Pitch, chord: Chord, bass: Option<Pitch>) -> ChordSymbol {
+        let pc = |p: Pitch| Pitch::from_interval(p.as_interval() % OCTAVE);
+        ChordSymbol {
+            root: pc(root),
+            chord,
+            bass: bass.map(pc),
+        }
+    }
+
+    /// The chord pitches above the root, without the bass note.
+    pub fn pitches(&self) -> Pitches<'_> {
+        self.chord.pitches(self.root)
+    }
+
+    /// Writes the symbol in `style`, spelling black-key roots as Db, Eb, F#, Ab
+    /// and Bb.
+    pub fn format(&self, style: SymbolStyle) -> String {
+        self.write(style, |p| {
+            let accidental = if p.as_interval() % OCTAVE == 6 {
+                Accidental::Sharp
+            } else {
+                Accidental::Flat
+            };
+            p.to_string_with_accidental(accidental)
+        })
+    }
+
+    /// Writes the symbol in `style`, spelling black-key roots with `accidental`.
+    pub fn format_with_accidental(&self, style: SymbolStyle, accidental: Accidental) -> String {
+        self.write(style, |p| p.to_string_with_accidental(accidental))
+    }
+
+    fn write<F>(&self, style: SymbolStyle, spell: F) -> String
+    where
+        F: Fn(Pitch) -> String,
+    {
+        let mut s = spell(self.root);
+        s.push_str(&Shape::new(&self.chord).write(style.tokens()));
+        if let Some(bass) = self.bass {
+            s.push('/');
+            s.push_str(&spell(bass));
+        }
+        s
+    }
+}
+
+impl fmt::Display for ChordSymbol {
+    /// Writes the symbol in `SymbolStyle::Pop`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.format(SymbolStyle::Pop))
+    }
+}
+
+/// A convention for writing chord symbols.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SymbolStyle {
+    /// "C△7", "C-7", "Cø7", "C7(b9,#11)".
+    Jazz,
+    /// "CM7", "Cm7", "Cø7", "C7(b9,#11)".
+    Classical,
+    /// "Cmaj7", "Cm7", "Cm7b5", "C7b9#11".
+    Pop,
+    /// "CMaj7", "C-7", "C-7(b5)", "C7(b9, #11)".
+    Berklee,
+    /// "CMA7", "C-7", "Cø7", "C7b9#11".
+    RealBook,
+}
+
+struct Tokens {
+    minor: &'static str,
+    major: &'static str,
+    minor_major: &'static str,
+    diminished: &'static str,
+    half_diminished: Option<&'static str>,
+    augmented: &'static str,
+    /// Whether alterations are always written in parentheses.
+    parentheses: bool,
+    separator: &'static str,
+}
+
+impl SymbolStyle {
+    fn tokens(self) -> Tokens {
+        use SymbolStyle::*;
+        let (minor, major, minor_major, diminished, half_diminished, augmented) = match self {
+            Jazz => ("-", "△", "-△", "°", Some("ø"), "+"),
+            Classical => ("m", "M", "mM", "°", Some("ø"), "+"),
+            Pop => ("m", "maj", "mMaj", "dim", None, "aug"),
+            Berklee => ("-", "Maj", "-Maj", "o", None, "+"),
+            RealBook => ("-", "MA", "-MA", "°", Some("ø"), "+"),
+        };
+        Tokens {
+            minor,
+            major,
+            minor_major,
+            diminished,
+            half_diminished,
+            augmented,
+            parentheses: !matches!(self, Pop | RealBook),
+            separator: if self == Berklee { ", " } else { "," },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Third {
+    Major,
+    Minor,
+    Sus2,
+    Sus4,
+    Omitted,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Fifth {
+    Perfect,
+    Diminished,
+    Augmented,
+    Flat,
+    Sharp,
+    Omitted,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Seventh {
+    Major,
+    Minor,
+    Diminished,
+}
+
+/// A chord taken apart into the pieces a symbol is written from.
+#[derive(Debug)]
+struct Shape {
+    third: Third,
+    fifth: Fifth,
+    seventh: Option<Seventh>,
+    sixth: bool,
+    /// The highest stacked extension of a seventh chord: 7, 9, 11 or 13.
+    extension: u8,
+    /// Tones left over once the rest is accounted for, as pitch classes.
+    tensions: Vec<u8>,
+    altered_dominant: bool,
+}
+
+impl Shape {
+    fn new(chord: &Chord) -> Shape {
+        let mut pcs = chord
+            .as_slice()
+            .iter()
+            .fold(0u16, |m, &i| m | 1 << (i % OCTAVE))
+            & !1;
+        let has = |pcs: u16, pc: u8| pcs & 1 << pc != 0;
+        // Altered dominants: a major third and minor seventh with only altered
+        // fifths and ninths.
+        let alterations = [1, 3, 6, 8].iter().filter(|&&pc| has(pcs, pc)).count();
+        let dominant = 1 << 4 | 1 << 10;
+        if pcs & dominant == dominant
+            && pcs & !(dominant | 1 << 1 | 1 << 3 | 1 << 6 | 1 << 8) == 0
+            && alterations >= 3
+        {
+            return Shape {
+                third: Third::Major,
+                fifth: Fifth::Omitted,
+                seventh: Some(Seventh::Minor),
+                sixth: false,
+                extension: 7,
+                tensions: Vec::new(),
+                altered_dominant: true,
+            };
+        }
+
+        let take = |pcs: &mut u16, pc: u8| {
+            let found = has(*pcs, pc);
+            *pcs &= !(1 << pc);
+            found
+        };
+
+        let third = if take(&mut pcs, 4) {
+            Third::Major
+        } else if take(&mut pcs, 3) {
+            Third::Minor
+        } else if take(&mut pcs, 5) {
+            Third::Sus4
+        } else if take(&mut pcs, 2) {
+            Third::Sus2
+        } else {
+            Third::Omitted
+        };
+        let fifth = if take(&mut pcs, 7) {
+            Fifth::Perfect
+        } else if third == Third::Minor && take(&mut pcs, 6) {
+            Fifth::Diminished
+        } else if third == Third::Major && take(&mut pcs, 8) {
+            Fifth::Augmented
+        } else if take(&mut pcs, 6) {
+            Fifth::Flat
+        } else if take(&mut pcs, 8) {
+            Fifth::Sharp
+        } else {
+            Fifth::Omitted
+        };
+        let seventh = if take(&mut pcs, 11) {
+            Some(Seventh::Major)
+        } else if take(&mut pcs, 10) {
+            Some(Seventh::Minor)
+        } else if fifth == Fifth::Diminished && take(&mut pcs, 9) {
+            Some(Seventh::Diminished)
+        } else {
+            None
+        };
+        let sixth = seventh.is_none() && take(&mut pcs, 9);
+        // An altered ninth still lets the eleventh or thirteenth name the chord.
+        let altered_ninth = has(pcs, 1) || has(pcs, 3);
+        let mut extension = 7;
+        let natural_ninth = seventh.is_some() && !altered_ninth && take(&mut pcs, 2);
+        if natural_ninth || (seventh.is_some() && altered_ninth) {
+            if natural_ninth {
+                extension = 9;
+            }
+            if take(&mut pcs, 9) {
+                extension = 13;
+            } else if take(&mut pcs, 5) {
+                extension = 11;
+            }
+        }
+        Shape {
+            third,
+            fifth,
+            seventh,
+            sixth,
+            extension,
+            tensions: (1..OCTAVE).filter(|&pc| has(pcs, pc)).collect(),
+            altered_dominant: false,
+        }
+    }
+
+    fn write(&self, tokens: Tokens) -> String {
+        if self.altered_dominant {
+            return String::from("7alt");
+        }
+        let mut s = String::new();
+        let mut altered = Vec::new();
+        let mut natural = Vec::new();
+        let mut added = Vec::new();
+
+        match (self.fifth, self.seventh) {
+            (Fifth::Diminished, None) | (Fifth::Diminished, Some(Seventh::Diminished)) => {
+                s.push_str(tokens.diminished)
+            }
+            (Fifth::Diminished, Some(Seventh::Minor)) if tokens.half_diminished.is_some() => {
+                s.push_str(tokens.half_diminished.unwrap())
+            }
+            (fifth, seventh) => {
+                let major = seventh == Some(Seventh::Major);
+                match self.third {
+                    Third::Minor if major => s.push_str(tokens.minor_major),
+                    Third::Minor => s.push_str(tokens.minor),
+                    _ => {}
+                }
+                if fifth == Fifth::Augmented {
+                    s.push_str(tokens.augmented);
+                }
+                if major && self.third != Third::Minor {
+                    s.push_str(tokens.major);
+                }
+                match fifth {
+                    Fifth::Diminished | Fifth::Flat => altered.push("b5"),
+                    Fifth::Sharp => altered.push("#5"),
+                    _ => {}
+                }
+            }
+        }
+
+        let power = self.third == Third::Omitted
+            && self.fifth == Fifth::Perfect
+            && self.seventh.is_none()
+            && !self.sixth
+            && self.tensions.is_empty();
+        // "6/9" names a natural ninth only, so an altered one beside it is
+        // written as an added ninth instead.
+        let six_nine = self.sixth
+            && self.tensions.contains(&2)
+            && !self.tensions.contains(&1)
+            && !self.tensions.contains(&3);
+        if six_nine {
+            s.push_str("6/9");
+        } else if self.sixth {
+            s.push('6');
+        } else if self.seventh.is_some() {
+            s.push_str(&self.extension.to_string());
+        } else if power {
+            s.push('5');
+        }
+        match self.third {
+            Third::Sus2 => s.push_str("sus2"),
+            Third::Sus4 => s.push_str("sus4"),
+            _ => {}
+        }
+
+        for &pc in &self.tensions {
+            let tension = match pc {
+                1 => "b9",
+                2 if six_nine => continue,
+                2 => "9",
+                3 => "#9",
+                5 => "11",
+                6 => "#11",
+                8 => "b13",
+                9 => "13",
+                _ => "#13",
+            };
+            if tension.starts_with(|c: char| c.is_ascii_digit()) {
+                if self.seventh.is_some() {
+                    natural.push(tension);
+                } else {
+                    added.push(tension);
+                }
+            } else {
+                altered.push(tension);
+            }
+        }
+        for tension in added {
+            s.push_str("add");
+            s.push_str(tension);
+        }
+
+        if self.third == Third::Omitted && !power {
+            natural.push("no3");
+        }
+        if self.fifth == Fifth::Omitted && !power {
+            natural.push("no5");
+        }
+        // An accidental straight after the root would be read as part of it.
+        if tokens.parentheses || s.is_empty() {
+            altered.extend(natural);
+            natural = altered;
+        } else {
+            s.push_str(&altered.concat());
+        }
+        if !natural.is_empty() {
+            s.push('(');
+            s.push_str(&natural.join(tokens.separator));
+            s.push(')');
+        }
+        s
+    }
+}
+
+/// The interval of the natural form of a chord degree above the root.
+fn natural(degree: u8) -> Option<u8> {
+    match degree {
+        2 => Some(2),
+        3 => Some(4),
+        4 => Some(5),
+        5 => Some(7),
+        6 => Some(9),
+        7 => Some(11),
+        9 => Some(14),
+        11 => Some(17),
+        13 => Some(21),
+        _ => None,
+    }
+}
+
+/// The chord tones collected while reading a symbol, by degree.
+#[derive(Debug)]
+struct Tones {
+    third: Option<u8>,
+    fifth: Vec<u8>,
+    sixth: bool,
+    seventh: Option<u8>,
+    ninth: Vec<u8>,
+    eleventh: Vec<u8>,
+    thirteenth: Vec<u8>,
+    added: Vec<u8>,
+    altered: [bool; 4],
+    major_seventh: bool,
+    diminished: bool,
+}
+
+impl Tones {
+    fn new() -> Tones {
+        Tones {
+            third: Some(4),
+            fifth: vec![7],
+            sixth: false,
+            seventh: None,
+            ninth: Vec::new(),
+            eleventh: Vec::new(),
+            thirteenth: Vec::new(),
+            added: Vec::new(),
+            altered: [false; 4],
+            major_seventh: false,
+            diminished: false,
+        }
+    }
+
+    fn seventh(&mut self) {
+        if self.seventh.is_none() {
+            self.seventh = Some(if self.major_seventh {
+                11
+            } else if self.diminished {
+                9
+            } else {
+                10
+            });
+        }
+    }
+
+    /// Apply an alteration of `delta` semitones to `degree`. The first
+    /// alteration of a degree replaces its natural form.
+    fn alter(&mut self, degree: u8, delta: i8) -> bool {
+        let (index, tones) = match degree {
+            5 => (0, &mut self.fifth),
+            2 | 9 => (1, &mut self.ninth),
+            4 | 11 => (2, &mut self.eleventh),
+            6 | 13 => (3, &mut self.thirteenth),
+            _ => return false,
+        };
+        if !self.altered[index] {
+            tones.clear();
+            self.altered[index] = true;
+        }
+        tones.push((natural(degree).unwrap() as i8 + delta) as u8);
+        true
+    }
+
+    fn chord(&self) -> Chord {
+        let mut chord = Chord::new();
+        let tones = self
+            .third
+            .iter()
+            .chain(&self.fifth)
+            .chain(self.sixth.then_some(&9))
+            .chain(&self.seventh)
+            .chain(&self.ninth)
+            .chain(&self.eleventh)
+            .chain(&self.thirteenth)
+            .chain(&self.added);
+        for &t in tones {
+            chord.insert(t);
+        }
+        chord
+    }
+}
+
+struct Parser<'s> {
+    s: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.s[self.pos..]
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.pos)
+    }
+
+    /// Consume the first of `tokens` the input starts with.
+    fn eat(&mut self, tokens: &[&'static str]) -> Option<&'static str> {
+        let rest = self.rest();
+        let token = tokens.iter().find(|t| rest.starts_with(*t))?;
+        self.pos += token.len();
+        Some(token)
+    }
+
+    fn eat_number(&mut self) -> Option<u8> {
+        let digits = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+        let n = self.rest()[..digits].parse().ok()?;
+        self.pos += digits;
+        Some(n)
+    }
+
+    fn eat_accidental(&mut self) -> Option<i8> {
+        match self.eat(&["#", "♯", "b", "♭"])? {
+            "#" | "♯" => Some(1),
+            _ => Some(-1),
+        }
+    }
+
+    fn note(&mut self) -> Result<Pitch, ParseError> {
+        let letter = match self.rest().chars().next() {
+            Some('C') => 0,
+            Some('D') => 2,
+            Some('E') => 4,
+            Some('F') => 5,
+            Some('G') => 7,
+            Some('A') => 9,
+            Some('B') => 11,
+            Some(_) => return Err(self.error(ParseErrorKind::InvalidToken)),
+            None => return Err(self.error(ParseErrorKind::UnexpectedEnd)),
+        };
+        self.pos += 1;
+        let delta = self.eat_accidental().unwrap_or(0);
+        Ok(Pitch::from_interval(
+            (letter + OCTAVE as i8 + delta) as u8 % OCTAVE,
+        ))
+    }
+
+    fn quality(&mut self, tones: &mut Tones) {
+        let major = if self.rest().starts_with("madd") {
+            None
+        } else {
+            self.eat(&["Δ", "△", "^", "maj", "Maj", "MA", "ma", "M"])
+        };
+        if let Some(token) = major {
+            tones.major_seventh = true;
+            if matches!(token, "Δ" | "△" | "^") {
+                tones.seventh = Some(11);
+            }
+        } else if self.eat(&["min", "mi", "m", "-"]).is_some() {
+            tones.third = Some(3);
+            if let Some(token) = self.eat(&["Δ", "△", "^", "maj", "Maj", "MA", "M"]) {
+                tones.major_seventh = true;
+                if matches!(token, "Δ" | "△" | "^") {
+                    tones.seventh = Some(11);
+                }
+            }
+        } else if !self.rest().starts_with("omit") && self.eat(&["dim", "°", "o"]).is_some() {
+            tones.third = Some(3);
+            tones.fifth = vec![6];
+            tones.diminished = true;
+        } else if self.eat(&["ø", "Ø"]).is_some() {
+            tones.third = Some(3);
+            tones.fifth = vec![6];
+            tones.seventh = Some(10);
+        } else if self.eat(&["aug", "+"]).is_some() {
+            tones.fifth = vec![8];
+            if let Some(token) = self.eat(&["Δ", "△", "^", "maj", "Maj", "MA", "M"]) {
+                tones.major_seventh = true;
+                if matches!(token, "Δ" | "△" | "^") {
+                    tones.seventh = Some(11);
+                }
+            }
+        }
+    }
+
+    fn extension(&mut self, tones: &mut Tones) -> Result<(), ParseError> {
+        if self.eat(&["6/9", "69"]).is_some() {
+            tones.sixth = true;
+            tones.ninth = vec![14];
+            return Ok(());
+        }
+        let start = self.pos;
+        let n = match self.eat_number() {
+            Some(n) => n,
+            None => return Ok(()),
+        };
+        match n {
+            5 => tones.third = None,
+            6 => tones.sixth = true,
+            7 => tones.seventh(),
+            9 | 11 | 13 => {
+                tones.seventh();
+                tones.ninth = vec![14];
+                match n {
+                    11 => tones.eleventh = vec![17],
+                    13 => tones.thirteenth = vec![21],
+                    _ => {}
+                }
+            }
+            _ => return Err(ParseError::new(ParseErrorKind::InvalidToken, start)),
+        }
+        Ok(())
+    }
+
+    /// Read one modifier, returning false if none was found.
+    fn modifier(&mut self, tones: &mut Tones, nested: bool) -> Result<bool, ParseError> {
+        let start = self.pos;
+        if self.eat(&["alt"]).is_some() {
+            tones.third = Some(4);
+            tones.seventh = Some(10);
+            tones.fifth.clear();
+            tones.ninth = vec![13, 15];
+            tones.eleventh = vec![18];
+            tones.thirteenth = vec![20];
+        } else if let Some(token) = self.eat(&["sus2", "sus4", "sus"]) {
+            tones.third = Some(if token == "sus2" { 2 } else { 5 });
+        } else if self.eat(&["add"]).is_some() {
+            let delta = self.eat_accidental().unwrap_or(0);
+            let degree = self.degree()?;
+            tones.added.push((degree as i8 + delta) as u8);
+        } else if self.eat(&["omit", "no"]).is_some() {
+            match self.eat_number() {
+                Some(3) => tones.third = None,
+                Some(5) => tones.fifth.clear(),
+                Some(_) => return Err(ParseError::new(ParseErrorKind::InvalidToken, start)),
+                None => return Err(self.error(self.missing())),
+            }
+        } else if nested
+            && self
+                .eat(&["maj7", "Maj7", "M7", "Δ7", "Δ", "△7", "△"])
+                .is_some()
+        {
+            tones.seventh = Some(11);
+        } else if let Some(delta) = self.eat(&["#", "♯", "+", "b", "♭", "-"]).map(|t| match t {
+            "#" | "♯" | "+" => 1,
+            _ => -1,
+        }) {
+            let at = self.pos;
+            let degree = self
+                .eat_number()
+                .ok_or_else(|| self.error(self.missing()))?;
+            if !tones.alter(degree, delta) {
+                return Err(ParseError::new(ParseErrorKind::InvalidToken, at));
+            }
+        } else if nested && self.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            let degree = self.degree()?;
+            tones.added.push(degree);
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn missing(&self) -> ParseErrorKind {
+        if self.rest().is_empty() {
+            ParseErrorKind::UnexpectedEnd
+        } else {
+            ParseErrorKind::InvalidToken
+        }
+    }
+
+    /// Read a degree number and return its natural interval.
+    fn degree(&mut self) -> Result<u8, ParseError> {
+        let start = self.pos;
+        let n = self
+            .eat_number()
+            .ok_or_else(|| self.error(self.missing()))?;
+        natural(n).ok_or_else(|| ParseError::new(ParseErrorKind::InvalidToken, start))
+    }
+
+    fn modifiers(&mut self, tones: &mut Tones) -> Result<(), ParseError> {
+        loop {
+            if self.eat(&["("]).is_some() {
+                loop {
+                    while self.eat(&[",", " "]).is_some() {}
+                    if self.eat(&[")"]).is_some() {
+                        break;
+                    }
+                    if !self.modifier(tones, true)? {
+                        return Err(self.error(self.missing()));
+                    }
+                }
+            } else if !self.modifier(tones, false)? {
+                return Ok(());
+            }
+        }
+    }
+
+    fn symbol(&mut self) -> Result<ChordSymbol, ParseError> {
+        if self.s.trim().is_empty() {
+            return Err(self.error(ParseErrorKind::Empty));
+        }
+        let root = self.note()?;
+        let mut tones = Tones::new();
+        self.quality(&mut tones);
+        self.extension(&mut tones)?;
+        self.modifiers(&mut tones)?;
+        let bass = if self.eat(&["/"]).is_some() {
+            Some(self.note()?)
+        } else {
+            None
+        };
+        if !self.rest().is_empty() {
+            return Err(self.error(ParseErrorKind::InvalidToken));
+        }
+        Ok(ChordSymbol {
+            root,
+            chord: tones.chord(),
+            bass,
+        })
+    }
+}
+
+impl FromStr for ChordSymbol {
+    type Err = ParseError;
+
+    /// Parse a lead-sheet chord symbol such as "F#m7b5", "Bb13(b9)" or "C6/9".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser { s, pos: 0 }.symbol()
+    }
+}
@@ -0,0 +1,113 @@
+use crate::OCTAVE;
+use crate::{Pitch, Scale};
+
+/// What a `DiatonicTransposer` does with pitches that are not in the scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChromaticPolicy {
+    /// Treat the pitch as an inflection of the scale tone below it, and keep
+    /// the same inflection on the transposed tone.
+    Preserve,
+    /// Move the pitch up to the next scale tone before transposing.
+    SnapUp,
+    /// Move the pitch down to the previous scale tone before transposing.
+    SnapDown,
+    /// Leave the pitch out, producing `None`.
+    Reject,
+}
+
+/// Transposes pitches by steps of a `Scale` rooted on a given pitch, so that a
+/// melody moved up a third in C major stays in C major.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiatonicTransposer {
+    scale: Scale,
+    root: Pitch,
+    policy: ChromaticPolicy,
+}
+
+impl DiatonicTransposer {
+    /// Creates a new `DiatonicTransposer` for `scale` rooted on `root`.
+    pub fn new(scale: Scale, root: Pitch, policy: ChromaticPolicy) -> DiatonicTransposer {
+        DiatonicTransposer {
+            scale,
+            root,
+            policy,
+        }
+    }
+
+    #[inline]
+    pub fn scale(&self) -> &Scale {
+        &self.scale
+    }
+
+    #[inline]
+    pub fn root(&self) -> Pitch {
+        self.root
+    }
+
+    #[inline]
+    pub fn policy(&self) -> ChromaticPolicy {
+        self.policy
+    }
+
+    /// The absolute scale degree of `pitch` counted from the root, and the
+    /// chromatic inflection to reapply after transposing.
+    fn locate(&self, pitch: Pitch) -> Option<(isize, i16)> {
+        let len = self.scale.len() as isize;
+        if len == 0 {
+            return None;
+        }
+        let octave = OCTAVE as i16;
+        let relative = pitch.as_interval() as i16 - self.root.as_interval() as i16;
+        let within = relative.rem_euclid(octave);
+        let octaves = relative.div_euclid(octave) as isize;
+        let (index, offset) = match self.scale.binary_search(&(within as u8)) {
+            Ok(i) => (i as isize, 0),
+            Err(i) => match self.policy {
+                ChromaticPolicy::Reject => return None,
+                ChromaticPolicy::SnapUp => (i as isize, 0),
+                ChromaticPolicy::SnapDown => (i as isize - 1, 0),
+                ChromaticPolicy::Preserve => {
+                    let below = i as isize - 1;
+                    let tone = if below < 0 {
+                        self.scale[len as usize - 1] as i16 - octave
+                    } else {
+                        self.scale[below as usize] as i16
+                    };
+                    (below, within - tone)
+                }
+            },
+        };
+        Some((octaves * len + index, offset))
+    }
+
+    /// Move `pitch` by `steps` scale degrees; negative steps move down.
+    ///
+    /// Returns `None` if the pitch is rejected by the chromatic policy or the
+    /// result is out of pitch range.
+    pub fn transpose(&self, pitch: Pitch, steps: isize) -> Option<Pitch> {
+        let (degree, offset) = self.locate(pitch)?;
+        let len = self.scale.len() as isize;
+        let degree = degree.checked_add(steps)?;
+        let octaves = degree.div_euclid(len);
+        let tone = self.scale[degree.rem_euclid(len) as usize] as isize;
+        let interval = octaves
+            .checked_mul(OCTAVE as isize)?
+            .checked_add(self.root.as_interval() as isize + tone + offset as isize)?;
+        if (0..=u8::MAX as isize).contains(&interval) {
+            Some(Pitch::from_interval(interval as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Move every pitch of `pitches` by `steps` scale degrees.
+    pub fn transpose_all<I>(&self, pitches: I, steps: isize) -> Vec<Option<Pitch>>
+    where
+        I: IntoIterator<Item = Pitch>,
+    {
+        pitches
+            .into_iter()
+            .map(|p| self.transpose(p, steps))
+            .collect()
+    }
+}
@@ -0,0 +1,225 @@
+use crate::OCTAVE;
+use crate::{Chord, Pitch, Voicing};
+
+/// The voice movement a `VoiceLeader` keeps as small as possible.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Motion {
+    /// The semitones moved by all voices together.
+    Total,
+    /// The semitones moved by the voice that moves furthest.
+    Maximum,
+}
+
+/// The movement of each voice from one voicing to the next.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VoiceLeading {
+    /// The new note of each voice, in the voice order of the old voicing.
+    pub pitches: Vec<Pitch>,
+    /// The semitones each voice moved, negative for downwards.
+    pub motions: Vec<i16>,
+    /// The semitones moved by all voices together.
+    pub total: u32,
+    /// The semitones moved by the voice that moves furthest.
+    pub maximum: u32,
+}
+
+impl VoiceLeading {
+    /// The new notes as a `Voicing`.
+    pub fn voicing(&self) -> Voicing {
+        Voicing::new(&self.pitches)
+    }
+
+    /// The distance measured by `motion`.
+    pub fn distance(&self, motion: Motion) -> u32 {
+        match motion {
+            Motion::Total => self.total,
+            Motion::Maximum => self.maximum,
+        }
+    }
+}
+
+/// Connects voicings to following chords with as little movement as possible.
+///
+/// Every voice moves to a tone of the next chord. When there are at least as
+/// many voices as tones, every tone is sounded; otherwise no tone is doubled.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VoiceLeader {
+    motion: Motion,
+    crossing: bool,
+    ranges: Vec<(Pitch, Pitch)>,
+}
+
+impl VoiceLeader {
+    /// Creates a new `VoiceLeader` minimising `motion`, with ties broken by the
+    /// other measure. Voices may only cross if `crossing` is set.
+    pub fn new(motion: Motion, crossing: bool) -> VoiceLeader {
+        VoiceLeader {
+            motion,
+            crossing,
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Keeps each voice, from the lowest up, within the inclusive range at the
+    /// same index. Voices without a range are unrestricted.
+    pub fn with_ranges(mut self, ranges: Vec<(Pitch, Pitch)>) -> VoiceLeader {
+        self.ranges = ranges;
+        self
+    }
+
+    #[inline]
+    pub fn motion(&self) -> Motion {
+        self.motion
+    }
+
+    #[inline]
+    pub fn crossing(&self) -> bool {
+        self.crossing
+    }
+
+    #[inline]
+    pub fn ranges(&self) -> &[(Pitch, Pitch)] {
+        &self.ranges
+    }
+
+    /// Leads `from` to `chord` on `root`. Returns `None` if the voice ranges
+    /// or crossing rule leave no way to do so.
+    pub fn lead(&self, from: &Voicing, chord: &Chord, root: Pitch) -> Option<VoiceLeading> {
+        let root = root.as_interval() % OCTAVE;
+        let pcs: Vec<u8> = chord.iter().map(|i| (root + i % OCTAVE) % OCTAVE).collect();
+        self.lead_to(from, &pcs)
+    }
+
+    /// Leads `from` to the pitch classes `target`, where 0 is C.
+    pub fn lead_to(&self, from: &Voicing, target: &[u8]) -> Option<VoiceLeading> {
+        let mut target: Vec<u8> = target.iter().map(|pc| pc % OCTAVE).collect();
+        target.sort_unstable();
+        target.dedup();
+        let voices: Vec<u8> = from.pitches().iter().map(Pitch::as_interval).collect();
+        if voices.is_empty() || target.is_empty() {
+            return None;
+        }
+        let candidates: Vec<Vec<(u8, usize)>> = voices
+            .iter()
+            .enumerate()
+            .map(|(v, &note)| self.candidates(v, note, &target))
+            .collect();
+        let mut search = Search {
+            leader: self,
+            voices: &voices,
+            candidates: &candidates,
+            cover: voices.len() >= target.len(),
+            chosen: Vec::new(),
+            used: vec![0; target.len()],
+            best: None,
+        };
+        search.run(0, 0, 0);
+        let (_, notes) = search.best?;
+        let motions: Vec<i16> = voices
+            .iter()
+            .zip(&notes)
+            .map(|(&a, &b)| b as i16 - a as i16)
+            .collect();
+        Some(VoiceLeading {
+            pitches: notes.iter().map(|&n| Pitch::from_interval(n)).collect(),
+            total: motions.iter().map(|m| m.unsigned_abs() as u32).sum(),
+            maximum: motions
+                .iter()
+                .map(|m| m.unsigned_abs() as u32)
+                .max()
+                .unwrap_or(0),
+            motions,
+        })
+    }
+
+    /// Leads `from` through each `(root, chord)` of a progression in turn.
+    /// Returns `None` if any step is impossible.
+    pub fn lead_progression(
+        &self,
+        from: &Voicing,
+        chords: &[(Pitch, Chord)],
+    ) -> Option<Vec<VoiceLeading>> {
+        let mut current = from.clone();
+        let mut steps = Vec::with_capacity(chords.len());
+        for (root, chord) in chords {
+            let step = self.lead(&current, chord, *root)?;
+            current = step.voicing();
+            steps.push(step);
+        }
+        Some(steps)
+    }
+
+    /// The notes voice `v` at `note` may move to, with the index of their
+    /// pitch class in `target`: the nearest of each pitch class above and
+    /// below it within its range.
+    fn candidates(&self, v: usize, note: u8, target: &[u8]) -> Vec<(u8, usize)> {
+        let (low, high) = self
+            .ranges
+            .get(v)
+            .map(|&(l, h)| (l.as_interval(), h.as_interval()))
+            .unwrap_or((0, u8::MAX));
+        let mut found = Vec::new();
+        for (t, &pc) in target.iter().enumerate() {
+            let notes = (low..=high).filter(|n| n % OCTAVE == pc);
+            let below = notes.clone().rfind(|&n| n <= note);
+            let above = notes.clone().find(|&n| n >= note);
+            found.extend(below.map(|n| (n, t)));
+            if above != below {
+                found.extend(above.map(|n| (n, t)));
+            }
+        }
+        found
+    }
+}
+
+/// A branch and bound search over the candidate notes of each voice.
+struct Search<'a> {
+    leader: &'a VoiceLeader,
+    voices: &'a [u8],
+    candidates: &'a [Vec<(u8, usize)>],
+    /// Whether every target pitch class must be sounded.
+    cover: bool,
+    chosen: Vec<u8>,
+    used: Vec<usize>,
+    best: Option<((u32, u32), Vec<u8>)>,
+}
+
+impl Search<'_> {
+    fn cost(&self, total: u32, maximum: u32) -> (u32, u32) {
+        match self.leader.motion {
+            Motion::Total => (total, maximum),
+            Motion::Maximum => (maximum, total),
+        }
+    }
+
+    fn run(&mut self, v: usize, total: u32, maximum: u32) {
+        let cost = self.cost(total, maximum);
+        if matches!(&self.best, Some((best, _)) if cost.0 > best.0) {
+            return;
+        }
+        let unsounded = self.used.iter().filter(|&&u| u == 0).count();
+        if self.cover && unsounded > self.voices.len() - v {
+            return;
+        }
+        if v == self.voices.len() {
+            if self.best.as_ref().is_none_or(|(best, _)| cost < *best) {
+                self.best = Some((cost, self.chosen.clone()));
+            }
+            return;
+        }
+        for &(note, t) in &self.candidates[v] {
+            if !self.cover && self.used[t] > 0 {
+                continue;
+            }
+            if !self.leader.crossing && self.chosen.last().is_some_and(|&prev| note < prev) {
+                continue;
+            }
+            let d = (note as i16 - self.voices[v] as i16).unsigned_abs() as u32;
+            self.chosen.push(note);
+            self.used[t] += 1;
+            self.run(v + 1, total + d, maximum.max(d));
+            self.used[t] -= 1;
+            self.chosen.pop();
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::dissonance::{harmonicity, roughness, tension};
+use crate::OCTAVE;
+use crate::{Chord, Pitch};
+
+/// An arrangement of chord tones in register, from the lowest note up.
+///
+/// Transforms that would move a note outside the range of `Pitch` panic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Voicing {
+    notes: Vec<u8>,
+}
+
+impl Voicing {
+    /// Creates a `Voicing` of `pitches`, in any order.
+    pub fn new(pitches: &[Pitch]) -> Voicing {
+        let mut notes: Vec<u8> = pitches.iter().map(Pitch::as_interval).collect();
+        notes.sort_unstable();
+        Voicing { notes }
+    }
+
+    /// The close position of `chord` on `root`: every chord tone once, within
+    /// the octave above the root. Compound intervals are reduced.
+    pub fn close(chord: &Chord, root: Pitch) -> Voicing {
+        let root = root.as_interval();
+        let mut notes: Vec<u8> = chord
+            .iter()
+            .map(|i| raise(root, i % OCTAVE, "close"))
+            .collect();
+        notes.sort_unstable();
+        notes.dedup();
+        Voicing { notes }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// The notes of the voicing, from the lowest up.
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.notes
+            .iter()
+            .map(|&n| Pitch::from_interval(n))
+            .collect()
+    }
+
+    /// The lowest note.
+    pub fn bass(&self) -> Option<Pitch> {
+        self.notes.first().map(|&n| Pitch::from_interval(n))
+    }
+
+    /// The highest note.
+    pub fn top(&self) -> Option<Pitch> {
+        self.notes.last().map(|&n| Pitch::from_interval(n))
+    }
+
+    /// The intervals of the voicing above its lowest note, including those
+    /// beyond the octave.
+    pub fn chord(&self) -> Chord {
+        let mut chord = Chord::new();
+        if let Some(&bass) = self.notes.first() {
+            for &n in &self.notes[1..] {
+                chord.insert(n - bass);
+            }
+        }
+        chord
+    }
+
+    /// Moves the lowest note above the highest, by whole octaves, `n` times:
+    /// the `n`th inversion of a close voicing.
+    pub fn invert(mut self, n: usize) -> Voicing {
+        if self.notes.len() < 2 {
+            return self;
+        }
+        for _ in 0..n {
+            let mut note = self.notes.remove(0);
+            while note <= *self.notes.last().unwrap() {
+                note = raise(note, OCTAVE, "invert");
+            }
+            self.notes.push(note);
+        }
+        self
+    }
+
+    /// Raises every other note an octave, starting with the second lowest, so
+    /// that the chord spans more than an octave.
+    pub fn open(mut self) -> Voicing {
+        for i in (1..self.notes.len()).step_by(2) {
+            self.notes[i] = raise(self.notes[i], OCTAVE, "open");
+        }
+        self.sorted()
+    }
+
+    /// Lowers each of `voices` an octave, counting the highest note as voice 1.
+    ///
+    /// ***Panics*** if a voice is out of bounds.
+    pub fn drop(mut self, voices: &[usize]) -> Voicing {
+        let len = self.notes.len();
+        for &voice in voices {
+            assert!(
+                (1..=len).contains(&voice),
+                "Voicing::drop: voice {} is out of bounds 1..={}",
+                voice,
+                len
+            );
+            let note = &mut self.notes[len - voice];
+            *note = note
+                .checked_sub(OCTAVE)
+                .expect("Voicing::drop: note is below C(0)");
+        }
+        self.sorted()
+    }
+
+    /// Lowers the second highest note an octave.
+    pub fn drop2(self) -> Voicing {
+        self.drop(&[2])
+    }
+
+    /// Lowers the third highest note an octave.
+    pub fn drop3(self) -> Voicing {
+        self.drop(&[3])
+    }
+
+    /// Lowers the second and fourth highest notes an octave.
+    pub fn drop24(self) -> Voicing {
+        self.drop(&[2, 4])
+    }
+
+    /// Keeps the lowest note and raises the rest an octave, leaving a gap
+    /// above the bass.
+    pub fn spread(mut self) -> Voicing {
+        for note in self.notes.iter_mut().skip(1) {
+            *note = raise(*note, OCTAVE, "spread");
+        }
+        self
+    }
+
+    /// Adds a copy of `voice` an octave higher, counting the lowest note as
+    /// voice 0.
+    ///
+    /// ***Panics*** if `voice` is out of bounds.
+    pub fn double(mut self, voice: usize) -> Voicing {
+        assert!(
+            voice < self.notes.len(),
+            "Voicing::double: voice {} is out of bounds 0..{}",
+            voice,
+            self.notes.len()
+        );
+        let note = raise(self.notes[voice], OCTAVE, "double");
+        self.notes.push(note);
+        self.sorted()
+    }
+
+    /// The sensory roughness of the voicing: the Plomp–Levelt dissonance,
+    /// after Sethares, between the first `partials` harmonics of every pair of
+    /// notes. Higher is rougher.
+    pub fn roughness(&self, partials: usize) -> f64 {
+        roughness(&self.notes, partials)
+    }
+
+    /// How nearly the notes form a harmonic series, between 0 and 1: the
+    /// reciprocal of the highest harmonic number when each note is tuned to a
+    /// just ratio above the bass. Higher is more consonant.
+    pub fn harmonicity(&self) -> f64 {
+        harmonicity(&self.notes)
+    }
+
+    /// The summed tension of the interval class between every pair of notes,
+    /// heaviest for semitones and tritones. Higher is more tense.
+    pub fn tension(&self) -> f64 {
+        tension(&self.notes)
+    }
+
+    fn sorted(mut self) -> Voicing {
+        self.notes.sort_unstable();
+        self
+    }
+}
+
+fn raise(note: u8, interval: u8, method: &str) -> u8 {
+    note.checked_add(interval)
+        .unwrap_or_else(|| panic!("Voicing::{}: note is above the range of Pitch", method))
+}
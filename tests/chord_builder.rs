@@ -0,0 +1,88 @@
+use musicode::{ChordErrorKind, Quality, Seventh};
+
+#[test]
+fn test_edits() {
+    let major = Quality::Major.chord();
+    let chord = major
+        .builder()
+        .with_seventh(Seventh::Minor)
+        .flat(9)
+        .sharp(11)
+        .build()
+        .unwrap();
+    assert_eq!(chord.as_slice(), [0, 4, 7, 10, 13, 18]);
+
+    let chord = major.builder().add(9).sus4().build().unwrap();
+    assert_eq!(chord.as_slice(), [0, 5, 7, 14]);
+
+    let chord = major.builder().with_seventh(Seventh::Major).omit(5).build();
+    assert_eq!(chord.unwrap().as_slice(), [0, 4, 11]);
+
+    let chord = Quality::Dominant9
+        .chord()
+        .builder()
+        .sharp(9)
+        .flat(9)
+        .build();
+    assert_eq!(chord.unwrap().as_slice(), [0, 4, 7, 10, 13, 15]);
+
+    let chord = Quality::Dominant7.chord().builder().sharp(5).build();
+    assert_eq!(chord.unwrap(), Quality::Augmented7.chord());
+
+    let chord = Quality::Diminished
+        .chord()
+        .builder()
+        .with_seventh(Seventh::Minor);
+    assert_eq!(chord.build().unwrap(), Quality::HalfDiminished7.chord());
+}
+
+#[test]
+fn test_alter_replaces_existing_alterations() {
+    let chord = Quality::HalfDiminished7.chord().builder().sharp(5).build();
+    assert_eq!(chord.unwrap().as_slice(), [0, 3, 8, 10]);
+
+    let chord = Quality::Augmented.chord().builder().flat(5).build();
+    assert_eq!(chord.unwrap().as_slice(), [0, 4, 6]);
+
+    let chord = Quality::Dominant7Flat9.chord().builder().sharp(9).build();
+    assert_eq!(chord.unwrap().as_slice(), [0, 4, 7, 10, 13, 15]);
+}
+
+#[test]
+fn test_rejected_edits() {
+    let major = Quality::Major.chord();
+    let kind = |r: Result<_, musicode::ChordError>| r.map(|_| ()).unwrap_err().kind();
+    assert_eq!(
+        kind(major.builder().add(8).build()),
+        ChordErrorKind::InvalidDegree
+    );
+    assert_eq!(
+        kind(major.builder().sharp(7).build()),
+        ChordErrorKind::InvalidAlteration
+    );
+    assert_eq!(
+        kind(major.builder().flat(11).build()),
+        ChordErrorKind::InvalidAlteration
+    );
+    assert_eq!(
+        kind(major.builder().omit(5).sharp(5).build()),
+        ChordErrorKind::Conflict
+    );
+    assert_eq!(
+        kind(major.builder().sharp(5).flat(5).build()),
+        ChordErrorKind::Conflict
+    );
+    assert_eq!(
+        kind(major.builder().sharp(11).add(11).build()),
+        ChordErrorKind::Conflict
+    );
+    let err = major
+        .builder()
+        .flat(13)
+        .omit(13)
+        .add(9)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.degree(), 13);
+    assert_eq!(err.to_string(), "conflicting edits of degree 13");
+}
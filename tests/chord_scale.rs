@@ -0,0 +1,76 @@
+use musicode::Pitch::*;
+use musicode::{Key, Mode, Quality, Scale};
+
+#[test]
+fn test_diatonic_chord_scales() {
+    let key = Key::new(C(0), Mode::MAJOR);
+
+    let tonic = key.chord_scale(C(0), &Quality::Major7.chord()).unwrap();
+    assert_eq!(tonic.name, Some("major"));
+    assert_eq!(tonic.chord_tones, [0, 4, 7, 11]);
+    assert_eq!(tonic.tensions, [14, 21]);
+    assert_eq!(tonic.avoid_notes, [17]);
+
+    let ii = key.chord_scale(D(0), &Quality::Minor7.chord()).unwrap();
+    assert_eq!(ii.name, Some("dorian"));
+    assert_eq!(ii.tensions, [14, 17, 21]);
+    assert!(ii.avoid_notes.is_empty());
+
+    let iii = key.chord_scale(E(0), &Quality::Minor7.chord()).unwrap();
+    assert_eq!(iii.name, Some("phrygian"));
+    assert_eq!(iii.avoid_notes, [13, 20]);
+
+    let v = key.chord_scale(G(0), &Quality::Dominant7.chord()).unwrap();
+    assert_eq!(v.name, Some("mixolydian"));
+    assert_eq!(v.tensions, [14, 21]);
+    assert_eq!(v.avoid_notes, [17]);
+
+    let vii = key
+        .chord_scale(B(0), &Quality::HalfDiminished7.chord())
+        .unwrap();
+    assert_eq!(vii.name, Some("locrian"));
+    assert_eq!(vii.tensions, [17, 20]);
+    assert_eq!(vii.avoid_notes, [13]);
+}
+
+#[test]
+fn test_non_diatonic_chords() {
+    let key = Key::new(C(0), Mode::MAJOR);
+    assert!(key.chord_scale(D(0), &Quality::Dominant7.chord()).is_none());
+    assert!(key.chord_scale(CD(0), &Quality::Major.chord()).is_none());
+    let harmonic = Scale::named("harmonic minor").unwrap();
+    let v = harmonic
+        .chord_scale(A(0), E(0), &Quality::Dominant7Flat9.chord())
+        .unwrap();
+    assert_eq!(v.name, Some("phrygian dominant"));
+    assert_eq!(v.tensions, [20]);
+    assert_eq!(v.avoid_notes, [17]);
+    let high = harmonic
+        .chord_scale(A(20), E(20), &Quality::Dominant7Flat9.chord())
+        .unwrap();
+    assert_eq!(high, v);
+    let major = Scale::named("major").unwrap();
+    let vii = major
+        .chord_scale(C(20), B(20), &Quality::HalfDiminished7.chord())
+        .unwrap();
+    assert_eq!(vii.name, Some("locrian"));
+}
+
+#[test]
+fn test_compatible_scales() {
+    let dominant = Quality::Dominant7.chord().compatible_scales(G(0));
+    let names: Vec<_> = dominant.iter().filter_map(|c| c.name).collect();
+    assert!(names.contains(&"mixolydian"));
+    assert!(names.contains(&"lydian dominant"));
+    assert!(names.contains(&"half-whole diminished"));
+    // The altered scale has no perfect fifth.
+    assert!(!names.contains(&"altered"));
+    assert!(dominant.iter().all(|c| c.root == G(0)));
+    assert!(dominant
+        .windows(2)
+        .all(|w| w[0].avoid_notes.len() <= w[1].avoid_notes.len()));
+
+    let major7 = Quality::Major7.chord().compatible_scales(C(0));
+    assert_eq!(major7[0].name, Some("lydian"));
+    assert!(major7[0].avoid_notes.is_empty());
+}
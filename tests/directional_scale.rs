@@ -0,0 +1,45 @@
+use musicode::{scale, Direction::*, DirectionalScale};
+
+fn melodic_minor() -> DirectionalScale {
+    DirectionalScale::new(scale![0, 2, 3, 5, 7, 9, 11], scale![0, 2, 3, 5, 7, 8, 10])
+}
+
+#[test]
+fn test_directional_pitches() {
+    use musicode::Pitch::*;
+    let scale = melodic_minor();
+    let up: Vec<_> = scale.pitches(A(4), Ascending).collect();
+    assert_eq!(up, [A(4), B(4), C(5), D(5), E(5), FG(5), GA(5)]);
+    let down: Vec<_> = scale.pitches(A(4), Descending).collect();
+    assert_eq!(down, [G(5), F(5), E(5), D(5), C(5), B(4), A(4)]);
+}
+
+#[test]
+fn test_degrees() {
+    let scale = melodic_minor();
+    assert_eq!(scale.degree(5, Ascending), Some(9));
+    assert_eq!(scale.degree(5, Descending), Some(8));
+    assert_eq!(scale.degree_of(11, Ascending), Some(6));
+    assert_eq!(scale.degree_of(11, Descending), None);
+    assert!(!scale.is_symmetric());
+}
+
+#[test]
+fn test_step() {
+    use musicode::Pitch::*;
+    let scale = melodic_minor();
+    assert_eq!(scale.step(A(4), E(5), Ascending), Some(FG(5)));
+    assert_eq!(scale.step(A(4), A(5), Descending), Some(G(5)));
+    assert_eq!(scale.step(A(4), GA(5), Ascending), Some(A(5)));
+    assert_eq!(scale.step(A(4), GA(5), Descending), Some(G(5)));
+    assert_eq!(scale.step(A(4), A(4), Descending), Some(G(4)));
+}
+
+#[test]
+fn test_directional_matches() {
+    let scale = melodic_minor();
+    let up: Vec<_> = scale.matches(Ascending, &[0, 4, 7]).collect();
+    assert_eq!(up, [vec![5, 9, 0], vec![7, 11, 2]]);
+    let down: Vec<_> = scale.matches(Descending, &[0, 4, 7]).collect();
+    assert_eq!(down, [vec![3, 7, 10], vec![8, 0, 3], vec![10, 2, 5]]);
+}
@@ -0,0 +1,70 @@
+use musicode::Pitch::*;
+use musicode::{IntervalSet, Mode, Pitch, Voicing};
+
+fn voicing(pitches: &[Pitch]) -> Voicing {
+    Voicing::new(pitches)
+}
+
+#[test]
+fn test_roughness() {
+    let unison = voicing(&[C(4), C(4)]);
+    let fifth = voicing(&[C(4), G(4)]);
+    let semitone = voicing(&[C(4), CD(4)]);
+    let tritone = voicing(&[C(4), FG(4)]);
+    assert!(unison.roughness(6) < tritone.roughness(6));
+    assert!(fifth.roughness(6) < tritone.roughness(6));
+    assert!(tritone.roughness(6) < semitone.roughness(6));
+    assert!(voicing(&[C(4)]).roughness(6) == 0.0);
+    // Close seconds are rougher low in the register.
+    assert!(voicing(&[C(2), D(2)]).roughness(1) > voicing(&[C(5), D(5)]).roughness(1));
+    // A spread voicing is smoother than a cluster of the same pitch classes.
+    let cluster = voicing(&[C(4), D(4), E(4)]);
+    let spread = voicing(&[C(3), E(4), D(5)]);
+    assert!(spread.roughness(6) < cluster.roughness(6));
+}
+
+#[test]
+fn test_harmonicity() {
+    assert_eq!(voicing(&[C(4), C(5)]).harmonicity(), 0.5);
+    assert_eq!(voicing(&[C(4), E(4), G(4)]).harmonicity(), 1.0 / 6.0);
+    assert_eq!(voicing(&[A(3), C(4), E(4)]).harmonicity(), 1.0 / 15.0);
+    // 2:3:5
+    assert_eq!(voicing(&[C(3), G(3), E(4)]).harmonicity(), 1.0 / 5.0);
+    assert_eq!(voicing(&[]).harmonicity(), 1.0);
+    let major = voicing(&[C(4), E(4), G(4)]).harmonicity();
+    let diminished = voicing(&[B(3), D(4), F(4)]).harmonicity();
+    assert!(major > diminished);
+}
+
+#[test]
+fn test_tension() {
+    assert_eq!(voicing(&[C(4), G(4)]).tension(), 0.1);
+    assert_eq!(voicing(&[C(4), C(5)]).tension(), 0.0);
+    let major = voicing(&[C(4), E(4), G(4)]).tension();
+    let dominant = voicing(&[G(3), B(3), D(4), F(4)]).tension();
+    let cluster = voicing(&[C(4), CD(4), D(4)]).tension();
+    assert!(major < dominant && dominant < cluster);
+    // Interval classes ignore register.
+    assert_eq!(
+        voicing(&[C(4), B(4)]).tension(),
+        voicing(&[C(4), CD(5)]).tension()
+    );
+    assert_eq!(IntervalSet::from(vec![0, 4, 7]).tension(), major);
+}
+
+#[test]
+fn test_rank_matches() {
+    let scale = Mode::MAJOR.scale();
+    let mut ranked: Vec<(Vec<u8>, f64)> = [&[0u8, 4, 7][..], &[0, 3, 7], &[0, 3, 6], &[0, 4, 10]]
+        .iter()
+        .flat_map(|&p| scale.matches(p).collect::<Vec<_>>())
+        .map(|m| {
+            let tension = IntervalSet::from(m.clone()).tension();
+            (m, tension)
+        })
+        .collect();
+    ranked.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    let major = IntervalSet::from(vec![0, 4, 7]).tension();
+    assert!((ranked.first().unwrap().1 - major).abs() < 1e-9);
+    assert_eq!(ranked.last().unwrap().0, vec![7, 11, 5]);
+}
@@ -0,0 +1,139 @@
+use musicode::Pitch::*;
+use musicode::{Fretboard, Quality};
+
+#[test]
+fn test_guitar_open_chords() {
+    let guitar = Fretboard::guitar();
+    let c = guitar.fingerings(C(0), &Quality::Major.chord(), Some(C(0)), false);
+    assert!(!c.is_empty());
+    // x32010
+    assert_eq!(
+        c[0].frets,
+        vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]
+    );
+    assert_eq!(c[0].bass, C(3));
+    assert_eq!(c[0].inversion, Some(0));
+    assert!(c.windows(2).all(|w| w[0].score <= w[1].score));
+
+    let any_bass = guitar.fingerings(C(0), &Quality::Major.chord(), None, false);
+    // x32010, not C/E at 032010
+    assert_eq!(
+        any_bass[0].frets,
+        vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]
+    );
+    let d = guitar.fingerings(D(0), &Quality::Major.chord(), None, false);
+    // xx0232
+    assert_eq!(
+        d[0].frets,
+        vec![None, None, Some(0), Some(2), Some(3), Some(2)]
+    );
+    let a = guitar.fingerings(A(0), &Quality::Major.chord(), None, false);
+    assert_eq!(a[0].inversion, Some(0));
+
+    let e = guitar.fingerings(E(0), &Quality::Major.chord(), None, false);
+    // 022100
+    assert_eq!(
+        e[0].frets,
+        vec![Some(0), Some(2), Some(2), Some(1), Some(0), Some(0)]
+    );
+}
+
+#[test]
+fn test_capo_and_stretch() {
+    let capo = Fretboard::guitar().with_capo(2);
+    assert_eq!(capo.capo(), 2);
+    // The C shape with a capo on the second fret sounds D.
+    let d = capo.fingerings(D(0), &Quality::Major.chord(), Some(D(0)), false);
+    assert!(d
+        .iter()
+        .any(|f| f.frets == vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]));
+
+    let narrow = Fretboard::guitar().with_stretch(2);
+    for f in narrow.fingerings(G(0), &Quality::Dominant7.chord(), None, false) {
+        let fretted: Vec<u8> = f
+            .frets
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|&f| f > 0)
+            .collect();
+        if let (Some(lo), Some(hi)) = (fretted.iter().min(), fretted.iter().max()) {
+            assert!(hi - lo < 2);
+        }
+    }
+}
+
+#[test]
+fn test_bass_and_inversions() {
+    let guitar = Fretboard::guitar();
+    let chord = Quality::Major.chord();
+    let first = guitar.fingerings(C(0), &chord, Some(E(0)), false);
+    assert!(!first.is_empty());
+    assert!(first.iter().all(|f| f.bass.as_interval() % 12 == 4));
+    assert!(first.iter().all(|f| f.inversion == Some(1)));
+
+    let any = guitar.fingerings(C(0), &chord, None, false);
+    assert!(any.iter().any(|f| f.inversion == Some(2)));
+}
+
+#[test]
+fn test_slash_bass_outside_the_chord() {
+    let guitar = Fretboard::guitar();
+    let c_over_d = guitar.fingerings(C(0), &Quality::Major.chord(), Some(D(0)), false);
+    assert!(!c_over_d.is_empty());
+    for f in &c_over_d {
+        assert_eq!(f.bass.as_interval() % 12, 2);
+        assert_eq!(f.inversion, None);
+        // The D sounds only in the bass.
+        assert_eq!(
+            f.pitches
+                .iter()
+                .filter(|p| p.as_interval() % 12 == 2)
+                .count(),
+            1
+        );
+    }
+    // xx0010: the open D under C, E and G.
+    let open = [None, None, Some(0), Some(0), Some(1), Some(0)];
+    assert!(c_over_d.iter().any(|f| f.frets == open));
+}
+
+#[test]
+fn test_omitted_fifth() {
+    let bass = Fretboard::bass();
+    let chord = Quality::Dominant7.chord();
+    let full = bass.fingerings(G(0), &chord, Some(G(0)), false);
+    assert!(full.iter().all(|f| f.omitted.is_empty()));
+    let shell = bass.fingerings(G(0), &chord, Some(G(0)), true);
+    assert!(shell.len() > full.len());
+    assert!(shell.iter().any(|f| f.omitted == vec![7]));
+}
+
+#[test]
+fn test_ukulele_and_alternate_tunings() {
+    let ukulele = Fretboard::ukulele();
+    let c = ukulele.fingerings(C(0), &Quality::Major.chord(), None, false);
+    // 0003, with the re-entrant G string above the C.
+    assert_eq!(c[0].frets, vec![Some(0), Some(0), Some(0), Some(3)]);
+    assert_eq!(c[0].bass, C(4));
+
+    let drop_d = Fretboard::new(vec![D(2), A(2), D(3), G(3), B(3), E(4)], 19);
+    let d = drop_d.fingerings(D(0), &Quality::Major.chord(), Some(D(0)), false);
+    assert_eq!(d[0].bass, D(2));
+    assert_eq!(d[0].frets[0], Some(0));
+}
+
+#[test]
+fn test_barre() {
+    let guitar = Fretboard::guitar();
+    let f = guitar.fingerings(F(0), &Quality::Major.chord(), Some(F(0)), false);
+    // 133211
+    assert_eq!(
+        f[0].frets,
+        vec![Some(1), Some(3), Some(3), Some(2), Some(1), Some(1)]
+    );
+    // A barre cannot hold the first fret over the open A string.
+    assert!(f
+        .iter()
+        .all(|f| f.frets != vec![Some(1), Some(0), Some(3), Some(2), Some(1), Some(1)]));
+}
@@ -0,0 +1,124 @@
+use musicode::Pitch::*;
+use musicode::{Cadence, Function, Key, Mode, Pitch, Quality};
+
+fn label(key: &Key, root: Pitch, quality: Quality) -> (Function, &'static str) {
+    let f = key.function(root, &quality.chord()).unwrap();
+    (f.function, f.label)
+}
+
+#[test]
+fn test_major_key_functions() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(label(&c, C(0), Quality::Major), (Function::Tonic, "T"));
+    assert_eq!(
+        label(&c, D(0), Quality::Minor7),
+        (Function::Subdominant, "Sp")
+    );
+    assert_eq!(label(&c, E(0), Quality::Minor), (Function::Dominant, "Dp"));
+    assert_eq!(
+        label(&c, F(0), Quality::Major7),
+        (Function::Subdominant, "S")
+    );
+    assert_eq!(
+        label(&c, G(0), Quality::Dominant7),
+        (Function::Dominant, "D")
+    );
+    assert_eq!(label(&c, A(0), Quality::Minor), (Function::Tonic, "Tp"));
+    assert_eq!(
+        label(&c, D(0), Quality::Dominant7),
+        (Function::Dominant, "(D)")
+    );
+    // Borrowed from C minor.
+    assert_eq!(
+        label(&c, F(0), Quality::Minor),
+        (Function::Subdominant, "s")
+    );
+    assert_eq!(
+        label(&c, CD(0), Quality::Major),
+        (Function::Subdominant, "sN")
+    );
+    assert_eq!(Function::Subdominant.to_string(), "S");
+}
+
+#[test]
+fn test_minor_key_functions() {
+    let a = Key::new(A(0), Mode::MINOR);
+    assert_eq!(label(&a, A(0), Quality::Minor), (Function::Tonic, "t"));
+    assert_eq!(label(&a, C(0), Quality::Major), (Function::Tonic, "tP"));
+    assert_eq!(label(&a, E(0), Quality::Minor), (Function::Dominant, "d"));
+    assert_eq!(label(&a, E(0), Quality::Major), (Function::Dominant, "D"));
+    assert_eq!(label(&a, G(0), Quality::Major), (Function::Dominant, "dP"));
+    assert_eq!(
+        label(&a, GA(0), Quality::Diminished7),
+        (Function::Dominant, "D")
+    );
+}
+
+#[test]
+fn test_cadences() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    let chords =
+        |cs: &[(Pitch, Quality)]| -> Vec<_> { cs.iter().map(|&(r, q)| (r, q.chord())).collect() };
+    let progression = chords(&[
+        (C(0), Quality::Major),
+        (F(0), Quality::Major),
+        (G(0), Quality::Dominant7),
+        (A(0), Quality::Minor),
+        (D(0), Quality::Minor),
+        (G(0), Quality::Major),
+        (C(0), Quality::Major),
+        (F(0), Quality::Major),
+        (C(0), Quality::Major),
+        (D(0), Quality::Minor),
+        (G(0), Quality::Major),
+    ]);
+    assert_eq!(
+        c.cadences(&progression),
+        [
+            (3, Cadence::Deceptive),
+            (6, Cadence::Authentic),
+            (8, Cadence::Plagal),
+            (10, Cadence::Half),
+        ]
+    );
+}
+
+#[test]
+fn test_half_cadence_only_at_end() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    let chords =
+        |cs: &[(Pitch, Quality)]| -> Vec<_> { cs.iter().map(|&(r, q)| (r, q.chord())).collect() };
+    let repeated = chords(&[
+        (C(0), Quality::Major),
+        (G(0), Quality::Major),
+        (G(0), Quality::Dominant7),
+        (C(0), Quality::Major),
+    ]);
+    assert_eq!(c.cadences(&repeated), [(3, Cadence::Authentic)]);
+    let plagal = chords(&[
+        (C(0), Quality::Major),
+        (G(0), Quality::Major),
+        (F(0), Quality::Major),
+        (C(0), Quality::Major),
+    ]);
+    assert_eq!(c.cadences(&plagal), [(3, Cadence::Plagal)]);
+}
+
+#[test]
+fn test_minor_key_cadences() {
+    let a = Key::new(A(0), Mode::MINOR);
+    let chords =
+        |cs: &[(Pitch, Quality)]| -> Vec<_> { cs.iter().map(|&(r, q)| (r, q.chord())).collect() };
+    let leading_tone = chords(&[
+        (D(0), Quality::Minor),
+        (GA(0), Quality::Diminished7),
+        (A(0), Quality::Minor),
+    ]);
+    assert_eq!(a.cadences(&leading_tone), [(2, Cadence::Authentic)]);
+    let half = chords(&[
+        (A(0), Quality::Minor),
+        (D(0), Quality::Minor),
+        (E(0), Quality::Major),
+    ]);
+    assert_eq!(a.cadences(&half), [(2, Cadence::Half)]);
+}
@@ -0,0 +1,78 @@
+use musicode::{scale, Quality, Stacking};
+
+#[test]
+fn test_major_triads() {
+    use Quality::*;
+    let scale = scale![0, 2, 4, 5, 7, 9, 11];
+    let qualities: Vec<_> = scale.triads().iter().map(|c| c.quality).collect();
+    assert_eq!(
+        qualities,
+        [
+            Some(Major),
+            Some(Minor),
+            Some(Minor),
+            Some(Major),
+            Some(Major),
+            Some(Minor),
+            Some(Diminished)
+        ]
+    );
+}
+
+#[test]
+fn test_harmonic_minor_sevenths() {
+    let scale = scale![0, 2, 3, 5, 7, 8, 11];
+    let names: Vec<_> = scale.sevenths().iter().map(|c| c.name()).collect();
+    assert_eq!(
+        names,
+        [
+            "minor major 7",
+            "half-diminished 7",
+            "augmented major 7",
+            "minor 7",
+            "dominant 7",
+            "major 7",
+            "diminished 7"
+        ]
+    );
+}
+
+#[test]
+fn test_ninths_wrap_octave() {
+    let scale = scale![0, 2, 4, 5, 7, 9, 11];
+    let ninths = scale.ninths();
+    assert_eq!(ninths[4].root, 7);
+    assert_eq!(ninths[4].chord.as_slice(), &[0, 4, 7, 10, 14]);
+    assert_eq!(ninths[2].quality, Some(Quality::Minor7Flat9));
+}
+
+#[test]
+fn test_quartal_stacking() {
+    let scale = scale![0, 2, 4, 7, 9];
+    let fourths = scale.harmonize(Stacking::Fourths, 3);
+    assert_eq!(fourths[0].chord.as_slice(), &[0, 7, 14]);
+    let fourths = scale![0, 2, 4, 5, 7, 9, 11].harmonize(Stacking::Fourths, 3);
+    assert_eq!(fourths[1].quality, Some(Quality::Quartal));
+    assert_eq!(fourths[3].chord.as_slice(), &[0, 6, 11]);
+    assert_eq!(fourths[3].quality, None);
+    assert_eq!(fourths[3].name(), "unnamed");
+}
+
+#[test]
+fn test_scale_chord_pitches() {
+    use musicode::Pitch::*;
+    let scale = scale![0, 2, 4, 5, 7, 9, 11];
+    let triads = scale.triads();
+    let pitches: Vec<_> = triads[1].pitches(C(4)).collect();
+    assert_eq!(pitches, [D(4), F(4), A(4)]);
+}
+
+#[test]
+fn test_wide_stacking_stops_in_range() {
+    let scale = scale![0, 2, 4, 5, 7, 9, 11];
+    let wide = scale.harmonize(Stacking::Steps(50), 4);
+    assert_eq!(wide[0].chord.as_slice(), &[0, 86, 172]);
+    assert_eq!(wide[6].chord.as_slice(), &[0, 85, 171]);
+    let huge = scale.harmonize(Stacking::Steps(usize::MAX), 3);
+    assert_eq!(huge[0].chord.as_slice(), &[0]);
+}
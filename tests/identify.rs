@@ -0,0 +1,76 @@
+use musicode::Pitch::*;
+use musicode::{Chord, Pitch, Quality};
+
+fn best(pitches: &[Pitch]) -> (Pitch, Quality, Option<usize>, String) {
+    let c = Chord::identify(pitches).remove(0);
+    (c.root, c.quality, c.inversion, c.symbol().to_string())
+}
+
+#[test]
+fn test_root_position_and_inversions() {
+    assert_eq!(
+        best(&[C(4), E(4), G(4)]),
+        (C(0), Quality::Major, Some(0), "C".to_string())
+    );
+    assert_eq!(
+        best(&[E(3), G(3), C(4)]),
+        (C(0), Quality::Major, Some(1), "C/E".to_string())
+    );
+    assert_eq!(
+        best(&[F(3), G(3), B(3), D(4)]),
+        (G(0), Quality::Dominant7, Some(3), "G7/F".to_string())
+    );
+    // Voicing order and doubling above the bass do not matter.
+    assert_eq!(best(&[A(2), E(5), C(4), A(3)]).1, Quality::Minor);
+}
+
+#[test]
+fn test_ambiguous_sets_are_ranked() {
+    let candidates = Chord::identify(&[C(3), E(3), G(3), A(3)]);
+    assert_eq!(
+        (candidates[0].root, candidates[0].quality),
+        (C(0), Quality::Major6)
+    );
+    assert!(candidates
+        .iter()
+        .any(|c| c.root == A(0) && c.quality == Quality::Minor7 && c.inversion == Some(1)));
+}
+
+#[test]
+fn test_slash_and_rootless() {
+    // A D major triad over a C bass.
+    let candidates = Chord::identify(&[C(3), D(4), FG(4), A(4)]);
+    assert!(candidates
+        .iter()
+        .any(|c| c.root == D(0) && c.quality == Quality::Major && c.inversion.is_none()));
+    // E G B D over nothing: a rootless C major 9.
+    let candidates = Chord::identify(&[E(3), G(3), B(3), D(4)]);
+    let rootless = candidates
+        .iter()
+        .find(|c| c.root == C(0) && c.quality == Quality::Major9)
+        .unwrap();
+    assert!(rootless.rootless);
+    assert_eq!(rootless.missing, [0]);
+    assert_eq!(candidates[0].quality, Quality::Minor7);
+}
+
+#[test]
+fn test_empty() {
+    assert!(Chord::identify(&[]).is_empty());
+    assert!(Chord::identify(&[C(4)]).is_empty());
+}
+
+#[test]
+fn test_doubled_slash_bass() {
+    // D sounds above the C triad as well as below it, so it is a chord tone.
+    let doubled = Chord::identify(&[D(2), C(3), E(3), G(3), D(4)]);
+    assert!(doubled.iter().all(|c| c.inversion.is_some()));
+    let (root, quality, inversion, symbol) = best(&[D(2), C(3), E(3), G(3), D(4)]);
+    assert_eq!((root, quality, inversion), (C(0), Quality::Add9, Some(3)));
+    assert_eq!(symbol, "Cadd9/D");
+    // Only a bass heard once can lie outside the chord.
+    let single = Chord::identify(&[D(2), C(3), E(3), G(3)]);
+    assert!(single
+        .iter()
+        .any(|c| c.root == C(0) && c.quality == Quality::Major && c.inversion.is_none()));
+}
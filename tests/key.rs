@@ -0,0 +1,70 @@
+use musicode::Pitch::*;
+use musicode::{Accidental, Key, KeySignature, Mode};
+
+#[test]
+fn test_signatures() {
+    let a_major = Key::new(A(0), Mode::MAJOR);
+    assert_eq!(a_major.signature().fifths(), 3);
+    assert_eq!(a_major.signature().accidentals(), ["F#", "C#", "G#"]);
+    let c_minor = Key::new(C(0), Mode::MINOR);
+    assert_eq!(c_minor.signature().accidentals(), ["Bb", "Eb", "Ab"]);
+    let d_dorian = Key::new(D(3), Mode::Dorian);
+    assert_eq!(d_dorian.signature().count(), 0);
+    assert_eq!(d_dorian.signature().accidental(), None);
+    assert_eq!(Key::new(FG(0), Mode::MAJOR).signature().fifths(), 6);
+    assert_eq!(Key::new(CD(0), Mode::MAJOR).signature().fifths(), -5);
+}
+
+#[test]
+fn test_enharmonic_spelling() {
+    let gb = Key::with_accidental(FG(0), Mode::MAJOR, Accidental::Flat);
+    assert_eq!(gb.signature().fifths(), -6);
+    assert_eq!(gb.to_string(), "Gb major");
+    let cs = Key::with_accidental(CD(0), Mode::MAJOR, Accidental::Sharp);
+    assert_eq!(cs.signature().fifths(), 7);
+    assert_eq!(cs.to_string(), "C# major");
+    let cb = Key::with_accidental(B(0), Mode::MAJOR, Accidental::Flat);
+    assert_eq!(cb.to_string(), "Cb major");
+    assert_eq!(cb.spell(E(0)), "Fb");
+    let g = Key::with_accidental(G(0), Mode::MAJOR, Accidental::Flat);
+    assert_eq!(g.signature().fifths(), 1);
+}
+
+#[test]
+fn test_related_keys() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(c.relative(), Key::new(A(0), Mode::MINOR));
+    assert_eq!(c.relative().relative(), c);
+    assert_eq!(c.parallel(), Key::new(C(0), Mode::MINOR));
+    assert_eq!(c.dominant(), Key::new(G(0), Mode::MAJOR));
+    assert_eq!(c.subdominant(), Key::new(F(0), Mode::MAJOR));
+    let e_phrygian = Key::new(E(0), Mode::Phrygian);
+    assert_eq!(e_phrygian.relative(), c);
+    assert_eq!(
+        Key::new(A(0), Mode::MINOR).dominant().to_string(),
+        "E minor"
+    );
+}
+
+#[test]
+fn test_fifths_distance() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(c.fifths_distance(&Key::new(E(0), Mode::MAJOR)), 4);
+    assert_eq!(c.fifths_distance(&Key::new(F(0), Mode::MAJOR)), 1);
+    assert_eq!(c.fifths_distance(&Key::new(A(0), Mode::MINOR)), 0);
+    assert_eq!(c.fifths_distance(&Key::new(FG(0), Mode::MAJOR)), 6);
+    let db = Key::new(CD(0), Mode::MAJOR);
+    let cs = Key::with_accidental(CD(0), Mode::MAJOR, Accidental::Sharp);
+    assert_eq!(db.fifths_distance(&cs), 0);
+}
+
+#[test]
+fn test_key_scale() {
+    let e_minor = Key::new(E(0), Mode::MINOR);
+    assert!(e_minor.contains(FG(4)));
+    assert!(!e_minor.contains(F(4)));
+    assert_eq!(e_minor.scale().as_slice(), &[0, 2, 3, 5, 7, 8, 10]);
+    assert_eq!(KeySignature::new(-2).accidentals(), ["Bb", "Eb"]);
+    let from_sig = Key::from_signature(KeySignature::new(-3), Mode::MINOR);
+    assert_eq!(from_sig, Key::new(C(0), Mode::MINOR));
+}
@@ -3,7 +3,30 @@
 extern crate musicode;
 // extern crate test;
 
+mod chord_builder;
+mod chord_scale;
+mod directional_scale;
+mod dissonance;
+mod fretboard;
+mod function;
+mod harmonize;
+mod identify;
 mod interval_set;
 mod iter;
+mod key;
+mod modulation;
+mod negative;
+mod neo_riemannian;
+mod numeral;
 mod pattern;
+mod periodic_scale;
+mod piano;
+mod progression;
+mod quantize;
+mod reharmonize;
 mod scale;
+mod scale_graph;
+mod symbol;
+mod transpose;
+mod voice_leading;
+mod voicing;
@@ -0,0 +1,88 @@
+use musicode::Pitch::*;
+use musicode::{Key, Mode, Quality};
+
+#[test]
+fn test_pivot_chords_c_to_g() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    let g = Key::new(G(0), Mode::MAJOR);
+    let pivots = c.pivot_chords(&g);
+    let triads: Vec<_> = pivots
+        .iter()
+        .filter(|p| p.chord.len() == 3)
+        .map(|p| (p.root, p.quality, p.from_degree, p.to_degree))
+        .collect();
+    assert_eq!(
+        triads,
+        [
+            (C(0), Some(Quality::Major), 0, 3),
+            (E(0), Some(Quality::Minor), 2, 5),
+            (G(0), Some(Quality::Major), 4, 0),
+            (A(0), Some(Quality::Minor), 5, 1),
+        ]
+    );
+    let sevenths: Vec<_> = pivots
+        .iter()
+        .filter(|p| p.chord.len() == 4)
+        .map(|p| p.root)
+        .collect();
+    assert_eq!(sevenths, [C(0), E(0), A(0)]);
+}
+
+#[test]
+fn test_no_pivots_between_distant_keys() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    let fs = Key::new(FG(0), Mode::MAJOR);
+    assert!(c.pivot_chords(&fs).is_empty());
+}
+
+#[test]
+fn test_harmonic_minor_pivots_keep_quality() {
+    // D, F and G♯ are all in A harmonic minor, but its chords on D and F are
+    // D minor and F major rather than C minor's D diminished and F minor.
+    let c = Key::new(C(0), Mode::MINOR);
+    let a = Key::new(A(0), Mode::HarmonicMinor);
+    assert!(c.pivot_chords(&a).is_empty());
+    assert!(a.pivot_chords(&c).is_empty());
+
+    let e = Key::new(E(0), Mode::HarmonicMinor);
+    let pivots: Vec<_> = a
+        .pivot_chords(&e)
+        .iter()
+        .map(|p| (p.root, p.quality))
+        .collect();
+    assert_eq!(pivots, [(A(0), Some(Quality::Minor))]);
+}
+
+#[test]
+fn test_modulation_paths() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    let g = Key::new(G(0), Mode::MAJOR);
+    let paths = c.modulation_paths(&g, 1, 10);
+    assert_eq!(paths[0].keys, [c, g]);
+    assert_eq!(paths[0].distance, 2);
+    assert!(paths.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+    let fs = Key::new(FG(0), Mode::MAJOR);
+    assert!(c.modulation_paths(&fs, 0, 10).is_empty());
+    let paths = c.modulation_paths(&fs, 2, 10);
+    let best = &paths[0];
+    assert_eq!(best.keys.len(), 4);
+    assert_eq!(best.pivots.len(), 3);
+    assert!(best.pivots.iter().all(|p| !p.is_empty()));
+}
+
+#[test]
+fn test_modulation_paths_limit() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    let fs = Key::new(FG(0), Mode::MAJOR);
+    let all = c.modulation_paths(&fs, 2, usize::MAX);
+    assert_eq!(all.len(), 8);
+    let best = c.modulation_paths(&fs, 2, 3);
+    assert_eq!(best[..], all[..3]);
+
+    let far = c.modulation_paths(&fs, 20, 5);
+    assert_eq!(far.len(), 5);
+    assert_eq!(far[0], all[0]);
+    assert!(far.windows(2).all(|w| w[0].distance <= w[1].distance));
+    assert!(far.iter().all(|p| p.pivots.iter().all(|s| !s.is_empty())));
+}
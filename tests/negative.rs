@@ -0,0 +1,69 @@
+use musicode::Pitch::*;
+use musicode::{Axis, IntervalSet, Key, Mode, Quality, Scale};
+
+#[test]
+fn test_invert() {
+    let set = IntervalSet::from(vec![0, 4, 7]);
+    assert_eq!(set.invert(7), IntervalSet::from(vec![0, 3, 7]));
+    assert_eq!(set.invert(0), IntervalSet::from(vec![0, 5, 8]));
+    let ninth = IntervalSet::from(vec![0, 4, 7, 10, 14]);
+    assert_eq!(ninth.invert(14), ninth);
+}
+
+#[test]
+fn test_reflect_chord() {
+    let axis = Axis::tonic_dominant(&Key::new(C(0), Mode::MAJOR));
+    assert_eq!(axis, Axis::new(C(0), G(0)));
+    assert_eq!(Axis::new(B(10), B(10)), Axis::new(B(0), B(0)));
+    assert_eq!(Axis::new(C(10), G(9)), axis);
+    assert_eq!(
+        axis.reflect_chord(C(0), &Quality::Major.chord()),
+        (C(0), Quality::Minor.chord())
+    );
+    assert_eq!(
+        axis.reflect_chord(G(2), &Quality::Dominant7.chord()),
+        (D(0), Quality::HalfDiminished7.chord())
+    );
+    assert_eq!(
+        axis.reflect_chord(F(0), &Quality::Major7.chord()),
+        (DE(0), Quality::Major7.chord())
+    );
+    assert_eq!(
+        axis.reflect_chord(D(0), &Quality::Minor.chord()),
+        (AB(0), Quality::Major.chord())
+    );
+
+    let other = Axis::tonic_dominant(&Key::new(A(0), Mode::MINOR));
+    assert_eq!(
+        other.reflect_chord(A(0), &Quality::Minor.chord()),
+        (A(0), Quality::Major.chord())
+    );
+}
+
+#[test]
+fn test_reflect_scale() {
+    let axis = Axis::new(C(0), G(0));
+    let major = Mode::MAJOR.scale();
+    assert_eq!(
+        axis.reflect_scale(C(0), &major),
+        (G(0), Mode::Phrygian.scale())
+    );
+    let (root, scale) = axis.reflect_scale(C(0), &Scale::chromatic());
+    assert_eq!((root, scale), (G(0), Scale::chromatic()));
+}
+
+#[test]
+fn test_reflect_pitches() {
+    let axis = Axis::new(C(0), G(0));
+    assert_eq!(axis.reflect(E(4)), DE(4));
+    assert_eq!(axis.reflect(C(4)), G(3));
+    assert_eq!(axis.reflect(G(4)), C(5));
+    assert_eq!(
+        axis.reflect_pitches(&[C(4), D(4), E(4), F(4)]),
+        vec![G(3), F(4), DE(4), D(4)]
+    );
+    // A pitch on the axis stays in place.
+    let fixed = Axis::new(D(0), D(0));
+    assert_eq!(fixed.reflect(D(3)), D(3));
+    assert_eq!(fixed.reflect(E(3)), C(3));
+}
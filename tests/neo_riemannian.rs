@@ -0,0 +1,98 @@
+use musicode::Pitch::*;
+use musicode::Transformation::*;
+use musicode::{Chord, Pitch, Quality, Transformation};
+
+fn major() -> Chord {
+    Quality::Major.chord()
+}
+
+fn minor() -> Chord {
+    Quality::Minor.chord()
+}
+
+#[test]
+fn test_transformations() {
+    assert_eq!(P.apply(C(4), &major()), Some((C(0), minor())));
+    assert_eq!(L.apply(C(0), &major()), Some((E(0), minor())));
+    assert_eq!(R.apply(C(0), &major()), Some((A(0), minor())));
+    assert_eq!(Slide.apply(C(0), &major()), Some((CD(0), minor())));
+    assert_eq!(N.apply(C(0), &major()), Some((F(0), minor())));
+    assert_eq!(H.apply(C(0), &major()), Some((GA(0), minor())));
+    assert_eq!(L.apply(C(0), &minor()), Some((GA(0), major())));
+    assert_eq!(N.apply(C(0), &minor()), Some((G(0), major())));
+    assert_eq!(H.apply(C(0), &minor()), Some((E(0), major())));
+    assert_eq!(P.apply(C(0), &Quality::Dominant7.chord()), None);
+    assert_eq!(major().transform(G(0), R), Some((E(0), minor())));
+}
+
+#[test]
+fn test_involutions_and_compounds() {
+    let compose = |ts: &[Transformation], root: Pitch, chord: Chord| {
+        ts.iter()
+            .fold((root, chord), |(r, c), t| t.apply(r, &c).unwrap())
+    };
+    for &t in &Transformation::ALL {
+        for root in C(0).iter().take(12) {
+            assert_eq!(compose(&[t, t], root, major()), (root, major()));
+        }
+    }
+    assert_eq!(
+        compose(&[L, P, R], D(0), major()),
+        compose(&[Slide], D(0), major())
+    );
+    assert_eq!(
+        compose(&[R, L, P], D(0), major()),
+        compose(&[N], D(0), major())
+    );
+    assert_eq!(
+        compose(&[L, P, L], D(0), major()),
+        compose(&[H], D(0), major())
+    );
+    assert_eq!(
+        compose(&[R, L, P], D(0), minor()),
+        compose(&[N], D(0), minor())
+    );
+}
+
+#[test]
+fn test_tonnetz() {
+    assert_eq!(C(4).tonnetz(), (0, 0));
+    assert_eq!(G(0).tonnetz(), (1, 0));
+    assert_eq!(E(0).tonnetz(), (0, 1));
+    assert_eq!(A(0).tonnetz(), (3, 0));
+    assert_eq!(major().tonnetz(C(0)), Some([(0, 0), (0, 1), (1, 0)]));
+    assert_eq!(minor().tonnetz(A(0)), Some([(3, 0), (4, -1), (4, 0)]));
+    assert_eq!(Quality::Diminished.chord().tonnetz(B(0)), None);
+}
+
+#[test]
+fn test_path() {
+    let plr = [P, L, R];
+    assert_eq!(
+        Transformation::path(C(0), &major(), C(0), &major(), &plr),
+        Some(vec![])
+    );
+    assert_eq!(
+        Transformation::path(C(0), &major(), E(0), &minor(), &plr),
+        Some(vec![L])
+    );
+    assert_eq!(
+        Transformation::path(C(0), &major(), GA(0), &minor(), &plr),
+        Some(vec![P, L, P])
+    );
+    assert_eq!(
+        Transformation::path(C(0), &major(), GA(0), &minor(), &Transformation::ALL),
+        Some(vec![H])
+    );
+    assert_eq!(
+        Transformation::path(C(0), &major(), D(0), &major(), &[P]),
+        None
+    );
+    let path = Transformation::path(C(0), &major(), FG(0), &major(), &plr).unwrap();
+    // R P R P: C, Am, A, F#m, F#.
+    assert_eq!(path.len(), 4);
+    let end = path
+        .iter()
+        .fold((C(0), major()), |(r, c), t| t.apply(r, &c).unwrap());
+    assert_eq!(end, (FG(0), major()));
+}
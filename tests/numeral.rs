@@ -0,0 +1,121 @@
+use musicode::Pitch::*;
+use musicode::{Key, Mode, ParseErrorKind, Quality, RomanNumeral};
+
+fn resolve(key: &Key, s: &str) -> (musicode::Pitch, Vec<u8>, Option<musicode::Pitch>) {
+    let sym = key.numeral(s).unwrap();
+    (sym.root, sym.chord.to_vec(), sym.bass)
+}
+
+fn label(key: &Key, root: musicode::Pitch, quality: Quality) -> String {
+    key.analyze(root, &quality.chord()).unwrap().to_string()
+}
+
+#[test]
+fn test_request_examples() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(resolve(&c, "ii7"), (D(0), vec![0, 3, 7, 10], None));
+    assert_eq!(resolve(&c, "V7/V"), (D(0), vec![0, 4, 7, 10], None));
+    assert_eq!(resolve(&c, "bVI"), (GA(0), vec![0, 4, 7], None));
+    assert_eq!(resolve(&c, "viiø7"), (B(0), vec![0, 3, 6, 10], None));
+    assert_eq!(resolve(&c, "N6"), (CD(0), vec![0, 4, 7], Some(F(0))));
+}
+
+#[test]
+fn test_inversions_and_secondaries() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(resolve(&c, "I64"), (C(0), vec![0, 4, 7], Some(G(0))));
+    assert_eq!(resolve(&c, "V42"), (G(0), vec![0, 4, 7, 10], Some(F(0))));
+    assert_eq!(resolve(&c, "vii°7/ii").0, CD(0));
+    assert_eq!(resolve(&c, "V/V/V").0, A(0));
+    let a = Key::new(A(0), Mode::MINOR);
+    assert_eq!(resolve(&a, "iv").0, D(0));
+    assert_eq!(resolve(&a, "V/III"), (G(0), vec![0, 4, 7], None));
+    assert_eq!(resolve(&a, "N").0, AB(0));
+}
+
+#[test]
+fn test_minor_key_leading_tone() {
+    let a = Key::new(A(0), Mode::MINOR);
+    assert_eq!(resolve(&a, "V7"), (E(0), vec![0, 4, 7, 10], None));
+    assert_eq!(resolve(&a, "vii°7"), (GA(0), vec![0, 3, 6, 9], None));
+    assert_eq!(resolve(&a, "viiø7"), (GA(0), vec![0, 3, 6, 10], None));
+    assert_eq!(resolve(&a, "VII").0, G(0));
+    assert_eq!(label(&a, E(0), Quality::Dominant7), "V7");
+    assert_eq!(label(&a, E(0), Quality::Minor), "v");
+    assert_eq!(label(&a, GA(0), Quality::Diminished7), "vii°7");
+    assert_eq!(label(&a, GA(0), Quality::Diminished), "vii°");
+    assert_eq!(label(&a, GA(0), Quality::HalfDiminished7), "viiø7");
+    assert_eq!(label(&a, G(0), Quality::Major), "VII");
+    assert_eq!(label(&a, B(0), Quality::Dominant7), "V7/V");
+}
+
+#[test]
+fn test_analysis() {
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(label(&c, D(0), Quality::Minor7), "ii7");
+    assert_eq!(label(&c, G(0), Quality::Dominant7), "V7");
+    assert_eq!(label(&c, B(0), Quality::HalfDiminished7), "viiø7");
+    assert_eq!(label(&c, D(0), Quality::Dominant7), "V7/V");
+    assert_eq!(label(&c, E(0), Quality::Major), "V/vi");
+    assert_eq!(label(&c, C(0), Quality::Dominant7), "V7/IV");
+    assert_eq!(label(&c, FG(0), Quality::Diminished7), "vii°7/V");
+    assert_eq!(label(&c, GA(0), Quality::Major), "bVI");
+    assert_eq!(label(&c, AB(0), Quality::Major), "bVII");
+    assert_eq!(label(&c, F(0), Quality::Minor), "iv");
+    assert_eq!(label(&c, CD(0), Quality::Major), "N");
+    assert!(c.analyze(C(0), &Quality::Quartal.chord()).is_none());
+}
+
+#[test]
+fn test_chromatic_roots_below_the_tonic() {
+    let c_minor = Key::new(C(0), Mode::MINOR);
+    assert_eq!(label(&c_minor, B(0), Quality::Major), "#VII");
+    assert_eq!(label(&c_minor, B(0), Quality::Minor), "vii");
+    assert_eq!(c_minor.numeral("#VII").unwrap().root, B(0));
+    let c = Key::new(C(0), Mode::MAJOR);
+    assert_eq!(label(&c, B(0), Quality::Minor), "vii");
+    assert_eq!(label(&c, C(0), Quality::Augmented), "I+");
+}
+
+#[test]
+fn test_round_trip() {
+    for s in &[
+        "ii7", "V65/V", "bVI", "viiø43", "N6", "IM7", "i64", "III+", "V9",
+    ] {
+        let numeral: RomanNumeral = s.parse().unwrap();
+        assert_eq!(numeral.to_string(), *s);
+    }
+    assert!("".parse::<RomanNumeral>().is_err());
+    assert!("X".parse::<RomanNumeral>().is_err());
+    assert!("Iø".parse::<RomanNumeral>().is_err());
+    assert!("V/".parse::<RomanNumeral>().is_err());
+}
+
+#[test]
+fn test_accidental_limit() {
+    let numeral: RomanNumeral = "bbVII".parse().unwrap();
+    assert_eq!(numeral.accidental(), -2);
+    let err = "b#bbbVII".parse::<RomanNumeral>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidToken);
+    assert_eq!(err.position(), 4);
+    let err = "♭♭♭II".parse::<RomanNumeral>().unwrap_err();
+    assert_eq!(err.position(), "♭♭".len());
+    let long = format!("{}I", "b".repeat(200));
+    assert!(long.parse::<RomanNumeral>().is_err());
+    assert!(RomanNumeral::new(2, 4, Quality::Major, 0, None).is_some());
+    assert!(RomanNumeral::new(3, 4, Quality::Major, 0, None).is_none());
+    assert!(RomanNumeral::new(i8::MIN, 4, Quality::Major, 0, None).is_none());
+}
+
+#[test]
+fn test_new() {
+    let v = RomanNumeral::new(0, 5, Quality::Major, 0, None).unwrap();
+    let v65 = RomanNumeral::new(0, 5, Quality::Dominant7, 1, Some(v)).unwrap();
+    assert_eq!(v65.to_string(), "V65/V");
+    assert_eq!(v65.secondary().map(|n| n.degree()), Some(5));
+    assert!(RomanNumeral::new(0, 0, Quality::Major, 0, None).is_none());
+    assert!(RomanNumeral::new(0, 8, Quality::Major, 0, None).is_none());
+    assert!(RomanNumeral::new(0, 1, Quality::Sus4, 0, None).is_none());
+    assert!(RomanNumeral::new(0, 1, Quality::Major, 3, None).is_none());
+    assert!(RomanNumeral::new(0, 5, Quality::Dominant9, 1, None).is_none());
+}
@@ -0,0 +1,77 @@
+use musicode::{PeriodicScale, Scale, StepNotation};
+
+#[should_panic]
+#[test]
+fn test_interval_panic() {
+    let mut set = PeriodicScale::new(19);
+    set.insert(19);
+}
+
+#[test]
+fn test_multi_octave_insert() {
+    let mut set = PeriodicScale::new(24);
+    assert_eq!(set.insert(14), (1, true));
+    assert_eq!(set.try_insert(23), Ok(2));
+    let err = set.try_insert(24).unwrap_err();
+    assert_eq!((err.interval(), err.period()), (24, 24));
+}
+
+#[test]
+fn test_from_steps() {
+    let set = PeriodicScale::from_steps("3 4 5 4 3", 19).unwrap();
+    assert_eq!(set.as_slice(), &[0, 3, 7, 12, 16]);
+    assert_eq!(set.to_steps(StepNotation::Numbers), "3 4 5 4 3");
+    assert!(PeriodicScale::from_steps("3 4 5 4 3", 12).is_err());
+}
+
+#[test]
+fn test_degree_pitches() {
+    use musicode::Pitch::*;
+    let set = PeriodicScale::from_steps("2 2 1 2 2", 9).unwrap();
+    let pitches: Vec<_> = set.pitches(C(4)).collect();
+    assert_eq!(pitches, [C(4), D(4), E(4), F(4), G(4)]);
+    assert_eq!(set.degree(C(4), 5), Some(A(4)));
+    assert_eq!(set.degree(C(4), 7), Some(CD(5)));
+}
+
+#[test]
+fn test_periodic_matches() {
+    let set = PeriodicScale::from_steps("2 2 1 2 2", 9).unwrap();
+    let fwd: Vec<_> = set.matches(&[0, 4, 7]).collect();
+    assert_eq!(fwd, [vec![0, 4, 7], vec![7, 2, 5]]);
+    let mut bwd: Vec<_> = set.rmatches(&[0, 4, 7]).collect();
+    bwd.reverse();
+    assert_eq!(bwd, fwd);
+}
+
+#[test]
+fn test_wide_period_matches() {
+    let set = PeriodicScale::from_steps("100 50 50", 200).unwrap();
+    let fwd: Vec<_> = set.matches(150).collect();
+    assert_eq!(fwd, [vec![0, 150], vec![150, 100]]);
+    let chords: Vec<_> = set.matches(&[0, 150]).collect();
+    assert_eq!(chords, fwd);
+}
+
+#[test]
+fn test_from_scale() {
+    let major: Scale = "W W H W W W H".parse().unwrap();
+    let set = PeriodicScale::from(major.clone());
+    assert_eq!(set.period(), 12);
+    assert_eq!(set.as_slice(), major.as_slice());
+    let triads: Vec<_> = set.harmonize(musicode::Stacking::Thirds, 3);
+    assert_eq!(triads, major.triads());
+}
+
+#[test]
+fn test_deserialize_checks_period() {
+    let set: PeriodicScale =
+        serde_json::from_str(r#"{"set":{"set":[0,3,7]},"period":19}"#).unwrap();
+    assert_eq!((set.as_slice(), set.period()), (&[0, 3, 7][..], 19));
+    assert!(serde_json::from_str::<PeriodicScale>(r#"{"set":{"set":[0]},"period":0}"#).is_err());
+    assert!(
+        serde_json::from_str::<PeriodicScale>(r#"{"set":{"set":[0,19]},"period":19}"#).is_err()
+    );
+    assert!(PeriodicScale::from_steps("3 4", 0).is_err());
+    assert!(PeriodicScale::from_steps("", 0).is_err());
+}
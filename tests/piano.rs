@@ -0,0 +1,86 @@
+use musicode::Pitch::*;
+use musicode::{PianoForm, PianoVoicer, Pitch, Quality};
+
+fn pcs(pitches: &[Pitch]) -> Vec<u8> {
+    pitches.iter().map(|p| p.as_interval() % 12).collect()
+}
+
+#[test]
+fn test_rootless() {
+    let voicer = PianoVoicer::new().with_left_range(C(3), C(5));
+    let d = Quality::Minor7.chord();
+    let a = voicer.voicings(D(0), &d, PianoForm::RootlessA);
+    // F A C E
+    assert_eq!(a[0].left, vec![F(3), A(3), C(4), E(4)]);
+    assert!(a[0].right.is_empty());
+    let b = voicer.voicings(D(0), &d, PianoForm::RootlessB);
+    assert_eq!(b[0].left, vec![C(3), E(3), F(3), A(3)]);
+
+    // The thirteenth replaces the fifth on dominant chords: B E F A.
+    let g = voicer.voicings(G(0), &Quality::Dominant7.chord(), PianoForm::RootlessB);
+    assert_eq!(pcs(&g[0].left), vec![5, 9, 11, 4]);
+    assert!(voicer
+        .voicings(C(0), &Quality::Major.chord(), PianoForm::RootlessA)
+        .is_empty());
+}
+
+#[test]
+fn test_low_interval_limits() {
+    let voicer = PianoVoicer::new().with_left_range(C(1), C(3));
+    // The B form of Dm7 has a major second from C to E, muddy below Eb3.
+    assert!(voicer
+        .voicings(D(0), &Quality::Minor7.chord(), PianoForm::RootlessB)
+        .is_empty());
+    // Shells with a root and seventh a tenth or more apart are allowed low.
+    let shells = voicer.voicings(C(0), &Quality::Major7.chord(), PianoForm::Shell);
+    for v in &shells {
+        let [low, high] = [v.left[0].as_interval(), v.left[1].as_interval()];
+        assert!(high - low == 11 && low >= 29 || high - low == 4 && low >= 34);
+    }
+}
+
+#[test]
+fn test_shell() {
+    let voicer = PianoVoicer::new();
+    let c9 = Quality::Dominant9.chord();
+    let shells = voicer.voicings(C(0), &c9, PianoForm::Shell);
+    assert!(!shells.is_empty());
+    for v in &shells {
+        assert_eq!(v.form, PianoForm::Shell);
+        assert_eq!(v.left[0].as_interval() % 12, 0);
+        let all = pcs(&v.voicing().pitches());
+        assert!(!all.contains(&7));
+        assert!(all.contains(&4) && all.contains(&10) && all.contains(&2));
+        assert!(v.right[0].as_interval() > v.left[1].as_interval());
+    }
+}
+
+#[test]
+fn test_upper_structures() {
+    let voicer = PianoVoicer::new();
+    let c7 = Quality::Dominant7.chord();
+    let found = voicer.voicings(C(0), &c7, PianoForm::UpperStructure);
+    // D major over E and Bb.
+    assert!(found
+        .iter()
+        .any(|v| v.left == vec![E(3), AB(3)] && v.right == vec![D(4), FG(4), A(4)]));
+    // Every triad root is used.
+    for root in [2, 3, 6, 8, 9] {
+        assert!(found.iter().any(|v| v.right[0].as_interval() % 12 == root));
+    }
+    assert!(voicer
+        .voicings(C(0), &Quality::Major7.chord(), PianoForm::UpperStructure)
+        .is_empty());
+}
+
+#[test]
+fn test_spans() {
+    let voicer = PianoVoicer::new().with_spans(6, 12);
+    assert_eq!(voicer.spans(), (6, 12));
+    let found = voicer.voicings(D(0), &Quality::Minor7.chord(), PianoForm::RootlessA);
+    assert!(found.is_empty());
+    let shells = voicer.voicings(C(0), &Quality::Major7.chord(), PianoForm::Shell);
+    assert!(shells
+        .iter()
+        .all(|v| v.left[1].as_interval() - v.left[0].as_interval() <= 6));
+}
@@ -0,0 +1,115 @@
+use musicode::Pitch::*;
+use musicode::{Key, Mode, Progression, Quality};
+
+fn progression(chords: &[(musicode::Pitch, Quality)]) -> Progression {
+    chords.iter().map(|&(r, q)| (r, q.chord(), 4)).collect()
+}
+
+#[test]
+fn test_push_and_duration() {
+    let mut p = Progression::new();
+    p.push(C(4), Quality::Major.chord(), 4);
+    p.push(G(3), Quality::Dominant7.chord(), 2);
+    assert_eq!(p.len(), 2);
+    assert_eq!(p[0].root, C(0));
+    assert_eq!(p[1].root, G(0));
+    assert_eq!(p.duration(), 6);
+}
+
+#[test]
+fn test_transpose() {
+    let p = progression(&[(C(0), Quality::Major), (A(0), Quality::Minor)]);
+    let up = p.transpose(3);
+    assert_eq!(up[0].root, DE(0));
+    assert_eq!(up[1].root, C(0));
+    assert_eq!(up[1].chord, Quality::Minor.chord());
+    assert_eq!(p.transpose(-2)[0].root, AB(0));
+}
+
+#[test]
+fn test_change_key() {
+    let p = progression(&[
+        (C(0), Quality::Major),
+        (F(0), Quality::Major),
+        (G(0), Quality::Dominant7),
+        (D(0), Quality::Dominant7),
+    ]);
+    let c = Key::new(C(0), Mode::MAJOR);
+    let a = Key::new(A(0), Mode::MINOR);
+    let moved = p.change_key(&c, &a);
+    assert_eq!(moved[0].root, A(0));
+    assert_eq!(moved[0].chord, Quality::Minor.chord());
+    assert_eq!(moved[1].root, D(0));
+    assert_eq!(moved[1].chord, Quality::Minor.chord());
+    assert_eq!(moved[2].root, E(0));
+    assert_eq!(moved[2].chord, Quality::Minor7.chord());
+    // The secondary dominant D7 is not diatonic, so it keeps its quality.
+    assert_eq!(moved[3].root, B(0));
+    assert_eq!(moved[3].chord, Quality::Dominant7.chord());
+
+    // Bb is borrowed from C minor, so it becomes the G of A minor and stays a
+    // major triad.
+    let borrowed = progression(&[(AB(0), Quality::Major), (C(0), Quality::Major)]);
+    let moved = borrowed.change_key(&c, &a);
+    assert_eq!(moved[0].root, G(0));
+    assert_eq!(moved[0].chord, Quality::Major.chord());
+    assert_eq!(moved[1].chord, Quality::Minor.chord());
+
+    // The Neapolitan and V7/iii keep their numerals.
+    let chromatic = progression(&[(CD(0), Quality::Major), (B(0), Quality::Dominant7)]);
+    let moved = chromatic.change_key(&c, &a);
+    assert_eq!((moved[0].root, moved[1].root), (AB(0), G(0)));
+    let neapolitan = progression(&[(AB(0), Quality::Major)]);
+    assert_eq!(neapolitan.change_key(&a, &c)[0].root, CD(0));
+
+    let g = Key::new(G(0), Mode::MAJOR);
+    assert_eq!(p.change_key(&c, &g), p.transpose(7));
+}
+
+#[test]
+fn test_in_key() {
+    let p = progression(&[
+        (D(0), Quality::Minor7),
+        (G(0), Quality::Dominant7),
+        (C(0), Quality::Major7),
+    ]);
+    let c = Key::new(C(0), Mode::MAJOR);
+    let numerals: Vec<String> = p.in_key(&c).map(|(_, n)| n.unwrap().to_string()).collect();
+    assert_eq!(numerals, vec!["ii7", "V7", "IM7"]);
+    assert_eq!(c.cadences(&p.pairs()).len(), 1);
+}
+
+#[test]
+fn test_ngrams() {
+    let p = progression(&[
+        (C(0), Quality::Major),
+        (G(0), Quality::Major),
+        (C(0), Quality::Major),
+        (G(0), Quality::Major),
+        (F(0), Quality::Major),
+    ]);
+    let bigrams = p.ngrams(2);
+    assert_eq!(bigrams.len(), 3);
+    assert_eq!(
+        bigrams[0],
+        (
+            vec![
+                (C(0), Quality::Major.chord()),
+                (G(0), Quality::Major.chord())
+            ],
+            2
+        )
+    );
+    assert_eq!(bigrams[1].1, 1);
+    assert_eq!(bigrams[1].0[0].0, G(0));
+    assert_eq!(bigrams[1].0[1].0, C(0));
+    assert!(p.ngrams(0).is_empty());
+    assert!(p.ngrams(6).is_empty());
+
+    let c = Key::new(C(0), Mode::MAJOR);
+    let f = Key::new(F(0), Mode::MAJOR);
+    let numerals = p.transpose(5).numeral_ngrams(&f, 3);
+    assert_eq!(numerals, p.numeral_ngrams(&c, 3));
+    let names: Vec<String> = numerals[0].0.iter().map(|n| n.to_string()).collect();
+    assert_eq!(names, vec!["I", "V", "I"]);
+}
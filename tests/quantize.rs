@@ -0,0 +1,64 @@
+use musicode::Pitch::*;
+use musicode::{scale, Quantized, Quantizer, Snap};
+
+#[test]
+fn test_snap_policies() {
+    let major = scale![0, 2, 4, 5, 7, 9, 11];
+    let mut up = Quantizer::new(major.clone(), D(0), Snap::Up);
+    let mut down = Quantizer::new(major.clone(), D(0), Snap::Down);
+    let mut nearest = Quantizer::new(major, D(0), Snap::Nearest);
+    // D major has F# and C#, so F and C are off the scale.
+    assert_eq!(up.quantize(F(4)).unwrap().pitch, FG(4));
+    assert_eq!(down.quantize(F(4)).unwrap().pitch, E(4));
+    assert_eq!(nearest.quantize(F(4)).unwrap().pitch, FG(4));
+    assert_eq!(
+        nearest.quantize(G(4)),
+        Some(Quantized {
+            pitch: G(4),
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn test_nearest_reports_offset() {
+    let pentatonic = scale![0, 2, 4, 7, 9];
+    let mut q = Quantizer::new(pentatonic, C(0), Snap::Nearest);
+    assert_eq!(
+        q.quantize(B(3)),
+        Some(Quantized {
+            pitch: C(4),
+            offset: 1
+        })
+    );
+    assert_eq!(
+        q.quantize(AB(3)),
+        Some(Quantized {
+            pitch: A(3),
+            offset: -1
+        })
+    );
+}
+
+#[test]
+fn test_toward_previous() {
+    let major = scale![0, 2, 4, 5, 7, 9, 11];
+    let mut q = Quantizer::new(major, C(0), Snap::TowardPrevious);
+    assert_eq!(q.quantize(CD(4)).unwrap().pitch, D(4));
+    assert_eq!(q.quantize(A(3)).unwrap().pitch, A(3));
+    assert_eq!(q.quantize(CD(4)).unwrap().pitch, C(4));
+    q.reset();
+    assert_eq!(q.previous(), None);
+}
+
+#[test]
+fn test_midi_and_frequency() {
+    let major = scale![0, 2, 4, 5, 7, 9, 11];
+    let mut q = Quantizer::new(major, C(0), Snap::Nearest);
+    assert_eq!(q.quantize_midi(61).unwrap().pitch, D(4));
+    let f = q.quantize_frequency(450.0).unwrap();
+    assert_eq!(f.pitch, A(4));
+    assert!((f.frequency - 440.0).abs() < 1e-9);
+    assert!((f.offset + 38.905).abs() < 1e-3);
+    assert_eq!(q.quantize_frequency(-1.0), None);
+}
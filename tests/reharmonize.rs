@@ -0,0 +1,163 @@
+use musicode::Pitch::*;
+use musicode::{Key, Mode, Pitch, Progression, Quality, Reharmonizer, Rule};
+
+fn progression(chords: &[(Pitch, Quality)]) -> Progression {
+    chords.iter().map(|&(r, q)| (r, q.chord(), 4)).collect()
+}
+
+fn roots(p: &Progression) -> Vec<(Pitch, Quality, u32)> {
+    p.iter()
+        .map(|c| (c.root, Quality::from_chord(&c.chord).unwrap(), c.duration))
+        .collect()
+}
+
+fn c_major() -> Key {
+    Key::new(C(0), Mode::MAJOR)
+}
+
+#[test]
+fn test_tritone_substitution() {
+    let p = progression(&[
+        (D(0), Quality::Minor7),
+        (G(0), Quality::Dominant7),
+        (C(0), Quality::Major7),
+    ]);
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::TritoneSubstitution])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].rule, Rule::TritoneSubstitution);
+    assert_eq!(found[0].index, 1);
+    assert_eq!(found[0].progression[1].root, CD(0));
+    assert_eq!(found[0].progression[1].chord, Quality::Dominant7.chord());
+    assert_eq!(found[0].explanation, "Db7 replaces G7 a tritone away");
+}
+
+#[test]
+fn test_secondary_dominant_and_two_five() {
+    let p = progression(&[
+        (C(0), Quality::Major),
+        (A(0), Quality::Minor),
+        (D(0), Quality::Minor),
+        (G(0), Quality::Major),
+    ]);
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::SecondaryDominant])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 3);
+    assert_eq!(found[1].index, 1);
+    assert_eq!(
+        roots(&found[1].progression),
+        vec![
+            (C(0), Quality::Major, 4),
+            (A(0), Quality::Dominant7, 4),
+            (D(0), Quality::Minor, 4),
+            (G(0), Quality::Major, 4),
+        ]
+    );
+    assert_eq!(
+        found[1].explanation,
+        "A7 (V7/ii) leads into Dm in place of Am"
+    );
+
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::TwoFive])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 3);
+    assert_eq!(found[2].explanation, "Am7 D7 approaches G in place of Dm");
+    assert_eq!(
+        roots(&found[0].progression)[..3],
+        [
+            (B(0), Quality::HalfDiminished7, 2),
+            (E(0), Quality::Dominant7, 2),
+            (A(0), Quality::Minor, 4),
+        ]
+    );
+    assert_eq!(found[0].explanation, "Bm7b5 E7 approaches Am in place of C");
+}
+
+#[test]
+fn test_diminished_passing() {
+    let p = progression(&[(C(0), Quality::Major), (D(0), Quality::Minor7)]);
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::DiminishedPassing])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 1);
+    assert_eq!(
+        roots(&found[0].progression),
+        vec![
+            (C(0), Quality::Major, 2),
+            (CD(0), Quality::Diminished7, 2),
+            (D(0), Quality::Minor7, 4),
+        ]
+    );
+}
+
+#[test]
+fn test_modal_interchange_and_relative_minor() {
+    let p = progression(&[(C(0), Quality::Major), (F(0), Quality::Major)]);
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::ModalInterchange])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[1].progression[1].chord, Quality::Minor.chord());
+    assert_eq!(
+        found[1].explanation,
+        "Fm (iv) is borrowed from C minor in place of F"
+    );
+
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::RelativeMinor])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].progression[0].root, A(0));
+    assert_eq!(found[1].progression[1].root, D(0));
+    assert_eq!(found[1].explanation, "Dm replaces its relative major F");
+
+    let g7 = progression(&[(G(0), Quality::Dominant7), (C(0), Quality::Major)]);
+    let found = Reharmonizer::new(c_major())
+        .with_rules(&[Rule::TwoFive, Rule::SecondaryDominant])
+        .reharmonize(&g7);
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_minor_key_numerals() {
+    let a = Key::new(A(0), Mode::MINOR);
+    let p = progression(&[(A(0), Quality::Minor), (D(0), Quality::Minor)]);
+    let found = Reharmonizer::new(a)
+        .with_rules(&[Rule::ModalInterchange])
+        .reharmonize(&p);
+    assert_eq!(
+        found[0].explanation,
+        "A (I) is borrowed from A major in place of Am"
+    );
+
+    let p = progression(&[(D(0), Quality::Minor), (A(0), Quality::Minor)]);
+    let found = Reharmonizer::new(a)
+        .with_rules(&[Rule::SecondaryDominant])
+        .reharmonize(&p);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].explanation, "E7 (V7) leads into Am in place of Dm");
+}
+
+#[test]
+fn test_rules_switch() {
+    let p = progression(&[(C(0), Quality::Major), (G(0), Quality::Dominant7)]);
+    let all = Reharmonizer::new(c_major());
+    assert_eq!(all.rules(), &Rule::ALL);
+    let without = all.clone().without(Rule::TritoneSubstitution);
+    assert!(all
+        .reharmonize(&p)
+        .iter()
+        .any(|r| r.rule == Rule::TritoneSubstitution));
+    assert!(without
+        .reharmonize(&p)
+        .iter()
+        .all(|r| r.rule != Rule::TritoneSubstitution));
+    assert!(Reharmonizer::new(c_major())
+        .with_rules(&[])
+        .reharmonize(&p)
+        .is_empty());
+    assert_eq!(Rule::TwoFive.to_string(), "ii–V approach");
+}
@@ -6,3 +6,39 @@ fn test_interval_panic() {
     let mut set = Scale::new();
     set.insert(12);
 }
+
+#[test]
+fn test_from_steps() {
+    use musicode::scale;
+    let major = scale![0, 2, 4, 5, 7, 9, 11];
+    assert_eq!(Scale::from_steps("W W H W W W H"), Ok(major.clone()));
+    assert_eq!(Scale::from_steps("2-2-1-2-2-2-1"), Ok(major.clone()));
+    assert_eq!(Scale::from_steps("TTSTTTS"), Ok(major.clone()));
+    assert_eq!("2212221".parse::<Scale>(), Ok(major));
+    assert_eq!(
+        Scale::from_steps("W H W W H A H"),
+        Ok(scale![0, 2, 3, 5, 7, 8, 11])
+    );
+}
+
+#[test]
+fn test_from_steps_errors() {
+    use musicode::ParseErrorKind::*;
+    let err = Scale::from_steps("W W X W W W H").unwrap_err();
+    assert_eq!((err.kind(), err.position()), (InvalidToken, 4));
+    let err = Scale::from_steps("W W H W W W").unwrap_err();
+    assert_eq!((err.kind(), err.position()), (StepSum(11), 11));
+    let err = Scale::from_steps("4 0 8").unwrap_err();
+    assert_eq!((err.kind(), err.position()), (ZeroStep, 2));
+    assert_eq!(Scale::from_steps("  ").unwrap_err().kind(), Empty);
+}
+
+#[test]
+fn test_to_steps() {
+    use musicode::{scale, StepNotation::*};
+    let major = scale![0, 2, 4, 5, 7, 9, 11];
+    assert_eq!(major.to_steps(Letters), "W W H W W W H");
+    assert_eq!(major.to_steps(Numbers), "2 2 1 2 2 2 1");
+    assert_eq!(major.to_steps(Tones), "TTSTTTS");
+    assert_eq!(scale![0, 4, 7].to_steps(Tones), "4 3 5");
+}
@@ -0,0 +1,64 @@
+use musicode::Pitch::*;
+use musicode::{scale, Scale, ScaleGraph};
+
+#[test]
+fn test_catalog() {
+    assert_eq!(Scale::named("dorian"), Some(scale![0, 2, 3, 5, 7, 9, 10]));
+    assert_eq!(scale![0, 2, 4, 6, 7, 9, 10].name(), Some("lydian dominant"));
+    assert_eq!(scale![0, 1, 2].name(), None);
+}
+
+#[test]
+fn test_common_tones() {
+    let major = Scale::named("major").unwrap();
+    let common = major.common_tones(C(4), &major, G(2));
+    assert_eq!(common, [C(0), D(0), E(0), G(0), A(0), B(0)]);
+}
+
+#[test]
+fn test_neighbours() {
+    let graph = ScaleGraph::new();
+    let major = Scale::named("major").unwrap();
+    let c = graph.find(C(0), &major).unwrap();
+    let mut names: Vec<_> = graph.neighbours(c).map(|n| (n.root, n.name)).collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            (C(0), "harmonic major"),
+            (C(0), "melodic minor"),
+            (D(0), "melodic minor"),
+            (F(0), "major"),
+            (G(0), "major"),
+            (A(0), "harmonic minor"),
+        ]
+    );
+}
+
+#[test]
+fn test_shortest_path() {
+    let graph = ScaleGraph::new();
+    let major = Scale::named("major").unwrap();
+    let path = graph.shortest_path((C(0), &major), (D(0), &major)).unwrap();
+    assert_eq!(path.len(), 3);
+    assert_eq!((path[1].root, path[1].name), (G(0), "major"));
+    let minor = Scale::named("minor").unwrap();
+    let path = graph.shortest_path((C(0), &major), (A(0), &minor)).unwrap();
+    assert_eq!(path.len(), 1);
+    let whole_tone = Scale::named("whole tone").unwrap();
+    assert!(graph
+        .shortest_path((C(0), &major), (C(0), &whole_tone))
+        .is_none());
+}
+
+#[test]
+fn test_nearest() {
+    let graph = ScaleGraph::new();
+    let major = Scale::named("major").unwrap();
+    let nearest = graph.nearest(C(0), &major, 6);
+    assert_eq!(nearest.len(), 6);
+    assert!(nearest.iter().all(|&(_, d)| d == 1));
+    let pentatonic = scale![0, 2, 4, 7, 9];
+    let nearest = graph.nearest(C(0), &pentatonic, 1);
+    assert_eq!(nearest[0].1, 1);
+}
@@ -0,0 +1,170 @@
+use musicode::Pitch::*;
+use musicode::{Accidental, Chord, ChordSymbol, ParseErrorKind, Quality, SymbolStyle};
+
+fn parse(s: &str) -> (musicode::Pitch, Vec<u8>, Option<musicode::Pitch>) {
+    let sym: ChordSymbol = s.parse().unwrap();
+    (sym.root, sym.chord.to_vec(), sym.bass)
+}
+
+#[test]
+fn test_request_examples() {
+    assert_eq!(
+        parse("Cmaj7#11/E"),
+        (C(0), vec![0, 4, 7, 11, 18], Some(E(0)))
+    );
+    assert_eq!(parse("F#m7b5"), (FG(0), vec![0, 3, 6, 10], None));
+    assert_eq!(parse("Bb13(b9)"), (AB(0), vec![0, 4, 7, 10, 13, 21], None));
+    assert_eq!(parse("Gsus4add9"), (G(0), vec![0, 5, 7, 14], None));
+    assert_eq!(parse("E7alt"), (E(0), vec![0, 4, 10, 13, 15, 18, 20], None));
+    assert_eq!(parse("C6/9"), (C(0), vec![0, 4, 7, 9, 14], None));
+}
+
+#[test]
+fn test_qualities() {
+    assert_eq!(parse("C").1, [0, 4, 7]);
+    assert_eq!(parse("Cm").1, [0, 3, 7]);
+    assert_eq!(parse("C-7").1, [0, 3, 7, 10]);
+    assert_eq!(parse("CΔ").1, [0, 4, 7, 11]);
+    assert_eq!(parse("CM9").1, [0, 4, 7, 11, 14]);
+    assert_eq!(parse("CmMaj7").1, [0, 3, 7, 11]);
+    assert_eq!(parse("Cm(maj7)").1, [0, 3, 7, 11]);
+    assert_eq!(parse("Cdim7").1, [0, 3, 6, 9]);
+    assert_eq!(parse("C°").1, [0, 3, 6]);
+    assert_eq!(parse("Cø7").1, [0, 3, 6, 10]);
+    assert_eq!(parse("C+").1, [0, 4, 8]);
+    assert_eq!(parse("C5").1, [0, 7]);
+    assert_eq!(parse("C7sus4").1, [0, 5, 7, 10]);
+    assert_eq!(parse("C11").1, [0, 4, 7, 10, 14, 17]);
+    assert_eq!(parse("C7(b9,#9)").1, [0, 4, 7, 10, 13, 15]);
+    assert_eq!(parse("C7(13)").1, [0, 4, 7, 10, 21]);
+    assert_eq!(parse("C7no5").1, [0, 4, 10]);
+    assert_eq!(parse("Cmaj7omit3").1, [0, 7, 11]);
+    assert_eq!(parse("Db/Ab"), (CD(0), vec![0, 4, 7], Some(GA(0))));
+}
+
+#[test]
+fn test_error_positions() {
+    let err = |s: &str| {
+        let e = s.parse::<ChordSymbol>().unwrap_err();
+        (e.kind(), e.position())
+    };
+    assert_eq!(err(""), (ParseErrorKind::Empty, 0));
+    assert_eq!(err("H7"), (ParseErrorKind::InvalidToken, 0));
+    assert_eq!(err("C8"), (ParseErrorKind::InvalidToken, 1));
+    assert_eq!(err("Cmaj7x"), (ParseErrorKind::InvalidToken, 5));
+    assert_eq!(err("C7b"), (ParseErrorKind::UnexpectedEnd, 3));
+    assert_eq!(err("C7(b9"), (ParseErrorKind::UnexpectedEnd, 5));
+    assert_eq!(err("C/"), (ParseErrorKind::UnexpectedEnd, 2));
+    assert_eq!(err("C7#3"), (ParseErrorKind::InvalidToken, 3));
+}
+
+fn format(s: &str, style: SymbolStyle) -> String {
+    s.parse::<ChordSymbol>().unwrap().format(style)
+}
+
+fn pitch_classes(chord: &Chord) -> Vec<u8> {
+    let mut pcs: Vec<u8> = chord.as_slice().iter().map(|i| i % 12).collect();
+    pcs.sort_unstable();
+    pcs.dedup();
+    pcs
+}
+
+#[test]
+fn test_format_styles() {
+    use SymbolStyle::*;
+    let styles = [Jazz, Classical, Pop, Berklee, RealBook];
+    let expect = |s: &str, symbols: [&str; 5]| {
+        for (&style, &symbol) in styles.iter().zip(&symbols) {
+            assert_eq!(format(s, style), symbol, "{} in {:?}", s, style);
+        }
+    };
+    expect("Cmaj7", ["C△7", "CM7", "Cmaj7", "CMaj7", "CMA7"]);
+    expect("Cm7", ["C-7", "Cm7", "Cm7", "C-7", "C-7"]);
+    expect("Cm7b5", ["Cø7", "Cø7", "Cm7b5", "C-7(b5)", "Cø7"]);
+    expect("Cdim7", ["C°7", "C°7", "Cdim7", "Co7", "C°7"]);
+    expect("CmMaj7", ["C-△7", "CmM7", "CmMaj7", "C-Maj7", "C-MA7"]);
+    expect("C+", ["C+", "C+", "Caug", "C+", "C+"]);
+    expect(
+        "C7(b9,#11)",
+        [
+            "C7(b9,#11)",
+            "C7(b9,#11)",
+            "C7b9#11",
+            "C7(b9, #11)",
+            "C7b9#11",
+        ],
+    );
+}
+
+#[test]
+fn test_format_request_examples() {
+    assert_eq!(format("Cmaj7#11/E", SymbolStyle::Pop), "Cmaj7#11/E");
+    assert_eq!(format("F#m7b5", SymbolStyle::Pop), "F#m7b5");
+    assert_eq!(format("Bb13(b9)", SymbolStyle::Jazz), "Bb13(b9)");
+    assert_eq!(format("Gsus4add9", SymbolStyle::Pop), "Gsus4add9");
+    assert_eq!(format("E7alt", SymbolStyle::Berklee), "E7alt");
+    assert_eq!(format("C6/9", SymbolStyle::Pop), "C6/9");
+}
+
+#[test]
+fn test_format_omissions_and_spelling() {
+    assert_eq!(format("C7no5", SymbolStyle::Pop), "C7(no5)");
+    assert_eq!(format("Cmaj7omit3", SymbolStyle::Jazz), "C△7(no3)");
+    assert_eq!(format("C5", SymbolStyle::Pop), "C5");
+    assert_eq!(format("C7(13)", SymbolStyle::Pop), "C7(13)");
+    assert_eq!(format("C(b5)", SymbolStyle::Pop), "C(b5)");
+    let sym = ChordSymbol::new(FG(3), Quality::Minor.chord(), None);
+    assert_eq!(sym.to_string(), "F#m");
+    let sym: ChordSymbol = "Db7/Ab".parse().unwrap();
+    assert_eq!(sym.to_string(), "Db7/Ab");
+    assert_eq!(
+        sym.format_with_accidental(SymbolStyle::Pop, Accidental::Sharp),
+        "C#7/G#"
+    );
+}
+
+#[test]
+fn test_format_round_trip() {
+    use SymbolStyle::*;
+    for quality in Quality::ALL.iter() {
+        let chord = quality.chord();
+        let sym = ChordSymbol::new(D(0), chord.clone(), None);
+        for &style in &[Jazz, Classical, Pop, Berklee, RealBook] {
+            let s = sym.format(style);
+            let parsed: ChordSymbol = s.parse().unwrap_or_else(|e| panic!("{}: {}", s, e));
+            assert_eq!(parsed.root, D(0), "{}", s);
+            assert_eq!(pitch_classes(&parsed.chord), pitch_classes(&chord), "{}", s);
+        }
+    }
+}
+
+#[test]
+fn test_format_extensions() {
+    assert_eq!(format("C9", SymbolStyle::Pop), "C9");
+    let mut both_ninths = Chord::new();
+    for &i in &[4, 7, 10, 13, 14] {
+        both_ninths.insert(i);
+    }
+    let sym = ChordSymbol::new(C(0), both_ninths, None);
+    assert_eq!(sym.format(SymbolStyle::Jazz), "C7(b9,9)");
+    assert_eq!(format("C13(#9)", SymbolStyle::Pop), "C13#9");
+    assert_eq!(format("C11", SymbolStyle::Classical), "C11");
+    assert_eq!(format("Cmaj9#11", SymbolStyle::Jazz), "C△9(#11)");
+
+    let mut altered_six_nine = Chord::new();
+    for &i in &[0, 1, 2, 3, 9] {
+        altered_six_nine.insert(i);
+    }
+    let sym = ChordSymbol::new(D(0), altered_six_nine.clone(), None);
+    for &style in &[SymbolStyle::Jazz, SymbolStyle::Pop] {
+        let s = sym.format(style);
+        assert!(!s.contains("6/9"), "{}", s);
+        let parsed: ChordSymbol = s.parse().unwrap_or_else(|e| panic!("{}: {}", s, e));
+        assert_eq!(
+            pitch_classes(&parsed.chord),
+            pitch_classes(&altered_six_nine),
+            "{}",
+            s
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use musicode::Pitch::*;
+use musicode::{scale, ChromaticPolicy, DiatonicTransposer};
+
+#[test]
+fn test_up_a_third() {
+    let t = DiatonicTransposer::new(
+        scale![0, 2, 4, 5, 7, 9, 11],
+        C(0),
+        ChromaticPolicy::Preserve,
+    );
+    let melody = [C(4), D(4), E(4), F(4), G(4), B(4)];
+    let moved = t.transpose_all(melody.iter().copied(), 2);
+    let expected = [E(4), F(4), G(4), A(4), B(4), D(5)];
+    assert_eq!(
+        moved,
+        expected.iter().copied().map(Some).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_down_across_octave() {
+    let t = DiatonicTransposer::new(scale![0, 2, 3, 5, 7, 8, 10], A(0), ChromaticPolicy::Reject);
+    assert_eq!(t.transpose(C(5), -3), Some(G(4)));
+    assert_eq!(t.transpose(A(4), -7), Some(A(3)));
+    assert_eq!(t.transpose(A(4), 9), Some(C(6)));
+}
+
+#[test]
+fn test_chromatic_policies() {
+    let major = scale![0, 2, 4, 5, 7, 9, 11];
+    let preserve = DiatonicTransposer::new(major.clone(), C(0), ChromaticPolicy::Preserve);
+    let up = DiatonicTransposer::new(major.clone(), C(0), ChromaticPolicy::SnapUp);
+    let down = DiatonicTransposer::new(major.clone(), C(0), ChromaticPolicy::SnapDown);
+    let reject = DiatonicTransposer::new(major, C(0), ChromaticPolicy::Reject);
+    // C# is a raised C, so up a step it becomes a raised D.
+    assert_eq!(preserve.transpose(CD(4), 1), Some(DE(4)));
+    assert_eq!(up.transpose(CD(4), 1), Some(E(4)));
+    assert_eq!(down.transpose(CD(4), 1), Some(D(4)));
+    assert_eq!(reject.transpose(CD(4), 1), None);
+}
+
+#[test]
+fn test_out_of_range() {
+    let t = DiatonicTransposer::new(
+        scale![0, 2, 4, 5, 7, 9, 11],
+        C(0),
+        ChromaticPolicy::Preserve,
+    );
+    assert_eq!(t.transpose(C(0), -1), None);
+    assert_eq!(t.transpose(C(4), isize::MAX), None);
+    assert_eq!(t.transpose(C(4), isize::MIN), None);
+    assert_eq!(t.transpose(C(4), 100_000), None);
+}
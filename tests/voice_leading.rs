@@ -0,0 +1,94 @@
+use musicode::Pitch::*;
+use musicode::{Motion, Quality, VoiceLeader, Voicing};
+
+fn c_major() -> Voicing {
+    Voicing::new(&[C(4), E(4), G(4)])
+}
+
+#[test]
+fn test_common_tones_are_kept() {
+    let leader = VoiceLeader::new(Motion::Total, false);
+    let step = leader
+        .lead(&c_major(), &Quality::Major.chord(), F(0))
+        .unwrap();
+    assert_eq!(step.pitches, [C(4), F(4), A(4)]);
+    assert_eq!(step.motions, [0, 1, 2]);
+    assert_eq!((step.total, step.maximum), (3, 2));
+
+    let step = leader
+        .lead(&c_major(), &Quality::Major.chord(), G(0))
+        .unwrap();
+    assert_eq!(step.pitches, [B(3), D(4), G(4)]);
+    assert_eq!(step.distance(Motion::Total), 3);
+}
+
+#[test]
+fn test_voice_counts() {
+    let leader = VoiceLeader::new(Motion::Total, false);
+    // Four voices onto a triad double one tone.
+    let four = Voicing::new(&[C(3), G(3), C(4), E(4)]);
+    let step = leader.lead(&four, &Quality::Minor.chord(), A(0)).unwrap();
+    assert_eq!(step.pitches, [C(3), A(3), C(4), E(4)]);
+    // Three voices onto a seventh chord leave a tone out but double none.
+    let step = leader
+        .lead(&c_major(), &Quality::Dominant7.chord(), G(0))
+        .unwrap();
+    assert_eq!(step.pitches, [B(3), F(4), G(4)]);
+}
+
+#[test]
+fn test_ranges_and_crossing() {
+    let leader = VoiceLeader::new(Motion::Total, false).with_ranges(vec![
+        (C(4), C(5)),
+        (C(4), C(5)),
+        (C(4), C(5)),
+    ]);
+    let step = leader
+        .lead(&c_major(), &Quality::Major.chord(), G(0))
+        .unwrap();
+    assert_eq!(step.pitches, [D(4), G(4), B(4)]);
+
+    let narrow = VoiceLeader::new(Motion::Total, false).with_ranges(vec![(A(0), A(0))]);
+    let bass = Voicing::new(&[C(1)]);
+    assert!(narrow.lead(&bass, &Quality::Major.chord(), C(0)).is_none());
+
+    let crossing = VoiceLeader::new(Motion::Total, true);
+    let wide = Voicing::new(&[E(4), F(4)]);
+    let step = crossing.lead_to(&wide, &[5, 4]).unwrap();
+    assert_eq!(step.total, 0);
+}
+
+#[test]
+fn test_maximum_motion() {
+    let voicing = Voicing::new(&[C(4), E(4), G(4), C(5)]);
+    let target = [1, 4, 7, 10];
+    let total = VoiceLeader::new(Motion::Total, false)
+        .lead_to(&voicing, &target)
+        .unwrap();
+    let maximum = VoiceLeader::new(Motion::Maximum, false)
+        .lead_to(&voicing, &target)
+        .unwrap();
+    assert!(maximum.maximum <= total.maximum);
+    assert!(total.total <= maximum.total);
+}
+
+#[test]
+fn test_progression() {
+    let leader = VoiceLeader::new(Motion::Total, false);
+    let chords = [
+        (A(0), Quality::Minor.chord()),
+        (F(0), Quality::Major.chord()),
+        (G(0), Quality::Major.chord()),
+        (C(0), Quality::Major.chord()),
+    ];
+    let steps = leader.lead_progression(&c_major(), &chords).unwrap();
+    assert_eq!(steps.len(), 4);
+    assert!(steps.iter().all(|s| s.maximum <= 2));
+    let mut last: Vec<u8> = steps[3]
+        .pitches
+        .iter()
+        .map(|p| p.as_interval() % 12)
+        .collect();
+    last.sort_unstable();
+    assert_eq!(last, [0, 4, 7]);
+}
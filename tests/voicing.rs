@@ -0,0 +1,53 @@
+use musicode::Pitch::*;
+use musicode::{Chord, Quality, Voicing};
+
+fn cmaj7() -> Voicing {
+    Quality::Major7.chord().voicing(C(4))
+}
+
+#[test]
+fn test_close_and_inversions() {
+    assert_eq!(cmaj7().pitches(), [C(4), E(4), G(4), B(4)]);
+    assert_eq!(cmaj7().invert(1).pitches(), [E(4), G(4), B(4), C(5)]);
+    assert_eq!(cmaj7().invert(3).pitches(), [B(4), C(5), E(5), G(5)]);
+    assert_eq!(cmaj7().invert(4), Quality::Major7.chord().voicing(C(5)));
+    // Compound intervals are brought into the octave.
+    let ninth = Quality::Dominant9.chord().voicing(C(4));
+    assert_eq!(ninth.pitches(), [C(4), D(4), E(4), G(4), AB(4)]);
+}
+
+#[test]
+fn test_open_and_spread() {
+    let triad = Quality::Major.chord().voicing(C(4));
+    assert_eq!(triad.clone().open().pitches(), [C(4), G(4), E(5)]);
+    assert_eq!(triad.spread().pitches(), [C(4), E(5), G(5)]);
+}
+
+#[test]
+fn test_drops() {
+    assert_eq!(cmaj7().drop2().pitches(), [G(3), C(4), E(4), B(4)]);
+    assert_eq!(cmaj7().drop3().pitches(), [E(3), C(4), G(4), B(4)]);
+    assert_eq!(cmaj7().drop24().pitches(), [C(3), G(3), E(4), B(4)]);
+    assert_eq!(
+        cmaj7().invert(1).drop2().pitches(),
+        [B(3), E(4), G(4), C(5)]
+    );
+}
+
+#[test]
+fn test_doubling_and_chord() {
+    let triad = Quality::Major.chord().voicing(C(4)).double(0);
+    assert_eq!(triad.pitches(), [C(4), E(4), G(4), C(5)]);
+    assert_eq!(triad.bass(), Some(C(4)));
+    assert_eq!(triad.top(), Some(C(5)));
+    let spread = cmaj7().drop2().chord();
+    assert_eq!(spread.as_slice(), [0, 5, 9, 16]);
+    assert_eq!(Voicing::new(&[E(4), C(4)]).chord().as_slice(), [0, 4]);
+    assert!(Voicing::new(&[]).chord() == Chord::new());
+}
+
+#[test]
+#[should_panic]
+fn test_drop_out_of_bounds() {
+    Quality::Major.chord().voicing(C(4)).drop(&[4]);
+}